@@ -0,0 +1,24 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # AccountDocument
+ * A document uploaded for an account, e.g. a photo of a passport.
+ * `content` is only sent when uploading, `id` and `created_at` only come back in responses.
+ */
+export type AccountDocument = { 
+/**
+ * e.g. `identity_verification`.
+ */
+document_type: string, 
+/**
+ * e.g. `passport`.
+ */
+document_sub_type?: string, 
+/**
+ * the base64 encoded file.
+ */
+content?: string, 
+/**
+ * e.g. `image/jpeg`.
+ */
+mime_type?: string, id?: string, created_at?: string, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # AccountStatus
+ * Where an account is in onboarding. Accounts can trade once they are `ACTIVE`.
+ */
+export type AccountStatus = "INACTIVE" | "ONBOARDING" | "SUBMITTED" | "SUBMISSION_FAILED" | "ACTION_REQUIRED" | "EDITED" | "ACCOUNT_UPDATED" | "APPROVAL_PENDING" | "APPROVED" | "REJECTED" | "ACTIVE" | "DISABLED" | "ACCOUNT_CLOSED" | "UNKNOWN";
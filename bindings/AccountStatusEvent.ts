@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AccountStatus } from "./AccountStatus";
+import type { JsonValue } from "./serde_json/JsonValue";
+
+/**
+ * # AccountStatusEvent
+ * an event of the account status stream (GET /v1/events/accounts/status),
+ * sent whenever an account's status or crypto status changes.
+ */
+export type AccountStatusEvent = { account_id: string, account_number: string, at: string, 
+/**
+ * the id to resume the stream from with `since_id`.
+ */
+event_id: number | null, 
+/**
+ * the id to resume the stream from with `since_ulid`.
+ */
+event_ulid: string | null, status_from: AccountStatus | null, status_to: AccountStatus | null, crypto_status_from: AccountStatus | null, crypto_status_to: AccountStatus | null, reason: string | null, 
+/**
+ * the outcome of the identity checks, when they ran.
+ */
+kyc_results: JsonValue | null, };
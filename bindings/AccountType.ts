@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # AccountType
+ * The kind of brokerage account. Defaults to `trading` on the server.
+ */
+export type AccountType = "trading" | "custodial" | "ira";
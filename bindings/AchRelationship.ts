@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AchRelationshipStatus } from "./AchRelationshipStatus";
+import type { BankAccountType } from "./BankAccountType";
+
+/**
+ * # AchRelationship
+ * a bank account linked to an account for ACH transfers.
+ */
+export type AchRelationship = { id: string, account_id: string, created_at: string, updated_at: string, status: AchRelationshipStatus, account_owner_name: string, bank_account_type: BankAccountType, bank_account_number: string, bank_routing_number: string, nickname: string | null, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # AchRelationshipStatus
+ * ACH transfers can use a relationship once it is `APPROVED`.
+ */
+export type AchRelationshipStatus = "QUEUED" | "APPROVED" | "PENDING" | "CANCEL_REQUESTED" | "CANCELED";
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AgreementType } from "./AgreementType";
+
+/**
+ * # Agreement
+ * An agreement the user signed, with where and when they signed it.
+ */
+export type Agreement = { agreement: AgreementType, signed_at: string, ip_address: string, 
+/**
+ * the revision of the agreement that was signed, e.g. `19.2022.02`.
+ */
+revision?: string, };
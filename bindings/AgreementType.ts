@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # AgreementType
+ * The agreements a user signs. Every account needs the customer agreement,
+ * margin, crypto and options trading need their own.
+ */
+export type AgreementType = "account_agreement" | "customer_agreement" | "margin_agreement" | "crypto_agreement" | "options_agreement";
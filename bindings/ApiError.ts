@@ -0,0 +1,28 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # ApiError
+ * the wire layout of an error body returned by the trading API.
+ * Only `code` and `message` are always present, the rest depend on the error.
+ */
+export type ApiError = { 
+/**
+ * Alpaca's 8 digit error code, e.g. `40310000`. Zero when the body had no code.
+ */
+code: number, message: string, 
+/**
+ * the orders that conflict with the rejected one, e.g. for potential wash trades.
+ */
+related_orders?: Array<string>, 
+/**
+ * the quantity available to trade when a qty is rejected.
+ */
+available?: string, existing_qty?: string, held_for_orders?: string, buying_power?: string, 
+/**
+ * the cost of the rejected order when buying power is insufficient.
+ */
+cost?: string, symbol?: string, 
+/**
+ * why a potential wash trade was rejected.
+ */
+reject_reason?: string, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # AssetClass
+ * Serves to identify the nature of the financial instrument,
+ * with options including "us_equity" for U.S. equities,
+ * "us_option" for U.S. options, and "crypto" for cryptocurrencies.
+ */
+export type AssetClass = "us_equity" | "us_option" | "crypto";
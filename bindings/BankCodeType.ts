@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # BankCodeType
+ * How a bank is identified, `ABA` routing numbers for US banks and `BIC` (SWIFT) otherwise.
+ */
+export type BankCodeType = "ABA" | "BIC";
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BankCodeType } from "./BankCodeType";
+import type { BankRelationshipStatus } from "./BankRelationshipStatus";
+
+/**
+ * # BankRelationship
+ * a bank account linked to an account for wire transfers.
+ */
+export type BankRelationship = { id: string, account_id: string, name: string, status: BankRelationshipStatus, bank_code: string, bank_code_type: BankCodeType, account_number: string, country: string | null, state_province: string | null, postal_code: string | null, city: string | null, street_address: string | null, created_at: string, updated_at: string, };
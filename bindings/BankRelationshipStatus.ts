@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # BankRelationshipStatus
+ */
+export type BankRelationshipStatus = "QUEUED" | "SENT_TO_CLEARING" | "APPROVED" | "CANCELED";
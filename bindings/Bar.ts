@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # Bar
+ * OHLC candlestick data of a stock over the bar's timeframe, e.g. a minute or a day.
+ */
+export type Bar = { 
+/**
+ * the start of the bar.
+ */
+t: string, o: number, h: number, l: number, c: number, v: number, n: number, vw: number, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Bar } from "./Bar";
+
+/**
+ * # Bars
+ * historical bars for each of the requested symbols (GET /v2/stocks/bars).
+ * `next_page_token` is set when there are more bars to fetch.
+ */
+export type Bars = { bars: { [key in string]?: Array<Bar> }, next_page_token: string | null, };
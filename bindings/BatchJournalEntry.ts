@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # BatchJournalEntry
+ * one receiving account of a [`BatchJournalRequest`].
+ */
+export type BatchJournalEntry = { to_account: string, amount?: string, symbol?: string, qty?: string, description?: string, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BatchJournalEntry } from "./BatchJournalEntry";
+import type { JournalEntryType } from "./JournalEntryType";
+
+/**
+ * # BatchJournalRequest
+ * moves cash or shares from one account, usually the firm's, to many (POST /v1/journals/batch).
+ */
+export type BatchJournalRequest = { entry_type: JournalEntryType, from_account: string, entries: Array<BatchJournalEntry>, };
@@ -0,0 +1,21 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AccountDocument } from "./AccountDocument";
+import type { AccountStatus } from "./AccountStatus";
+import type { AccountType } from "./AccountType";
+import type { Agreement } from "./Agreement";
+import type { AssetClass } from "./AssetClass";
+import type { Contact } from "./Contact";
+import type { Disclosures } from "./Disclosures";
+import type { Identity } from "./Identity";
+import type { TrustedContact } from "./TrustedContact";
+
+/**
+ * # BrokerAccount
+ * an end user's account, as returned when creating, getting or listing accounts.
+ * Listing accounts leaves out most of the identity and the documents.
+ */
+export type BrokerAccount = { id: string, account_number: string, status: AccountStatus, 
+/**
+ * the status of crypto trading, which is approved separately.
+ */
+crypto_status: AccountStatus | null, currency: string, last_equity: string | null, created_at: string, account_type: AccountType | null, contact: Contact | null, identity: Identity | null, disclosures: Disclosures | null, agreements: Array<Agreement>, documents: Array<AccountDocument>, trusted_contact: TrustedContact | null, enabled_assets: Array<AssetClass>, };
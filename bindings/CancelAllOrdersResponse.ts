@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OrderResult } from "./OrderResult";
+
+/**
+ * The response we get for each order when we cancel all orders.
+ * `body` holds the canceled order, or the error when the order failed to cancel.
+ */
+export type CancelAllOrdersResponse = { id: string, status: number, body: OrderResult, };
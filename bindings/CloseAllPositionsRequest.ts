@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # CloseAllPositionsRequest
+ * closes every open position (DELETE /v2/positions).
+ * Each position's result is a [`super::CloseAllPositionsResponse`].
+ */
+export type CloseAllPositionsRequest = { 
+/**
+ * if true, cancels all open orders before liquidating the positions.
+ */
+cancel_orders?: boolean, };
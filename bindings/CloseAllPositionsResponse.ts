@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OrderResult } from "./OrderResult";
+
+/**
+ * The response we get when we close a position.
+ * `body` holds the closing order, or the error when the position failed to close.
+ */
+export type CloseAllPositionsResponse = { symbol: string, status: number, body: OrderResult, };
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # ClosePositionRequest
+ * closes (liquidates) a single position (DELETE /v2/positions/{symbol_or_asset_id}).
+ * Without `qty` or `percentage` the whole position is closed.
+ */
+export type ClosePositionRequest = { 
+/**
+ * the number of shares to liquidate. Cannot be used with `percentage`.
+ */
+qty?: string, 
+/**
+ * the percent of the position to liquidate, up to 100. Cannot be used with `qty`.
+ */
+percentage?: string, };
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # Contact
+ * How to reach the account holder.
+ */
+export type Contact = { email_address: string, phone_number?: string, 
+/**
+ * one entry per line of the address.
+ */
+street_address: Array<string>, unit?: string, city?: string, 
+/**
+ * required for US addresses.
+ */
+state?: string, postal_code?: string, 
+/**
+ * ISO 3166-1 alpha-3 code, e.g. `USA`.
+ */
+country?: string, };
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AccountDocument } from "./AccountDocument";
+import type { AccountType } from "./AccountType";
+import type { Agreement } from "./Agreement";
+import type { AssetClass } from "./AssetClass";
+import type { Contact } from "./Contact";
+import type { Disclosures } from "./Disclosures";
+import type { Identity } from "./Identity";
+import type { TrustedContact } from "./TrustedContact";
+
+/**
+ * # CreateAccountRequest
+ * opens an account for an end user (POST /v1/accounts).
+ */
+export type CreateAccountRequest = { account_type?: AccountType, contact: Contact, identity: Identity, disclosures: Disclosures, agreements: Array<Agreement>, documents?: Array<AccountDocument>, trusted_contact?: TrustedContact, 
+/**
+ * the asset classes the account can trade, `us_equity` when left out.
+ */
+enabled_assets?: Array<AssetClass>, };
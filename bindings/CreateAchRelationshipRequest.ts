@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BankAccountType } from "./BankAccountType";
+
+/**
+ * # CreateAchRelationshipRequest
+ * links a bank account for ACH transfers (POST /v1/accounts/{account_id}/ach_relationships).
+ * Either pass the bank details or a Plaid `processor_token`.
+ */
+export type CreateAchRelationshipRequest = { account_owner_name: string, bank_account_type?: BankAccountType, bank_account_number?: string, bank_routing_number?: string, nickname?: string, 
+/**
+ * a Plaid processor token, used instead of the bank details.
+ */
+processor_token?: string, };
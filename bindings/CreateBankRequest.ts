@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BankCodeType } from "./BankCodeType";
+
+/**
+ * # CreateBankRequest
+ * links a bank account for wire transfers (POST /v1/accounts/{account_id}/recipient_banks).
+ * The address is only needed for international banks.
+ */
+export type CreateBankRequest = { 
+/**
+ * the name of the bank.
+ */
+name: string, bank_code: string, bank_code_type: BankCodeType, account_number: string, country?: string, state_province?: string, postal_code?: string, city?: string, street_address?: string, };
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FeePaymentMethod } from "./FeePaymentMethod";
+import type { TransferDirection } from "./TransferDirection";
+import type { TransferTiming } from "./TransferTiming";
+import type { TransferType } from "./TransferType";
+
+/**
+ * # CreateTransferRequest
+ * moves money in or out of an account (POST /v1/accounts/{account_id}/transfers).
+ * `ach` transfers need a `relationship_id`, `wire` transfers a `bank_id`.
+ */
+export type CreateTransferRequest = { transfer_type: TransferType, relationship_id?: string, bank_id?: string, amount: string, direction: TransferDirection, timing: TransferTiming, fee_payment_method?: FeePaymentMethod, 
+/**
+ * free text sent along with wire transfers.
+ */
+additional_information?: string, };
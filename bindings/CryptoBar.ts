@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * CryptoBar represents OHLC candlestick data (minute or daily)
+ * The differen between Bar types is the volume, as cryptocurrencies are usually traded in fractional amounts.
+ */
+export type CryptoBar = { t: string, o: number, h: number, l: number, c: number, v: number, n: number, vw: number, };
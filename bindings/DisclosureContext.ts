@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DisclosureContextType } from "./DisclosureContextType";
+
+/**
+ * # DisclosureContext
+ * The details behind a disclosure, e.g. the firm a user is affiliated with.
+ */
+export type DisclosureContext = { context_type: DisclosureContextType, company_name?: string, company_street_address?: Array<string>, company_city?: string, company_state?: string, company_country?: string, company_compliance_email?: string, 
+/**
+ * the exposed family member, for `IMMEDIATE_FAMILY_EXPOSED`.
+ */
+given_name?: string, family_name?: string, };
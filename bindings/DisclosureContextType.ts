@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # DisclosureContextType
+ * What a [`DisclosureContext`] explains.
+ */
+export type DisclosureContextType = "AFFILIATE_FIRM" | "CONTROLLED_FIRM" | "IMMEDIATE_FAMILY_EXPOSED";
@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DisclosureContext } from "./DisclosureContext";
+import type { EmploymentStatus } from "./EmploymentStatus";
+
+/**
+ * # Disclosures
+ * The regulatory disclosures of the account holder.
+ */
+export type Disclosures = { is_control_person: boolean, is_affiliated_exchange_or_finra: boolean, is_politically_exposed: boolean, immediate_family_exposed: boolean, employment_status?: EmploymentStatus, employer_name?: string, employer_address?: string, employment_position?: string, 
+/**
+ * required when any of the flags above is true.
+ */
+context?: Array<DisclosureContext>, };
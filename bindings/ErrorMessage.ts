@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # ErrorMessage
+ * defines the general error message received when an error has occured calling the API.
+ * * `code`: the error code belonging to this error, either an http status code or
+ *   Alpaca's own 8 digit error code (e.g. `40310000`).
+ * * `message`: describes what went wrong.
+ */
+export type ErrorMessage = { code: number, message: string, };
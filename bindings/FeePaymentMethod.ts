@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # FeePaymentMethod
+ * Who pays the transfer fee, the `user` out of the transfer or the firm on its `invoice`.
+ */
+export type FeePaymentMethod = "user" | "invoice";
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # FundingSource
+ * Where the money in an account comes from.
+ */
+export type FundingSource = "employment_income" | "investments" | "inheritance" | "business_income" | "savings" | "family";
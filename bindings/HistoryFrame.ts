@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ *  An alternative structure to convert Portfolio History into frames.
+ */
+export type HistoryFrame = { time: string, equity: number | null, profit_loss: number | null, profit_loss_percent: number | null, base_value: number, base_value_as_of: string, cashflow: { [key in string]?: number }, };
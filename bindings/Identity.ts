@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FundingSource } from "./FundingSource";
+
+/**
+ * # Identity
+ * Who the account holder is. Alpaca leaves `tax_id` out of responses.
+ */
+export type Identity = { given_name: string, middle_name?: string, family_name: string, date_of_birth?: string, tax_id?: string, 
+/**
+ * e.g. `USA_SSN`.
+ */
+tax_id_type?: string, 
+/**
+ * ISO 3166-1 alpha-3 code, e.g. `USA`.
+ */
+country_of_citizenship?: string, country_of_birth?: string, country_of_tax_residence?: string, funding_source: Array<FundingSource>, annual_income_min?: string, annual_income_max?: string, liquid_net_worth_min?: string, liquid_net_worth_max?: string, total_net_worth_min?: string, total_net_worth_max?: string, 
+/**
+ * only for users without US citizenship or permanent residence, e.g. `H1B`.
+ */
+visa_type?: string, visa_expiration_date?: string, date_of_departure_from_usa?: string, permanent_resident?: boolean, };
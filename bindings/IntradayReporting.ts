@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # IntradayReporting
+ * Which timestamps intraday portfolio history points are reported for.
+ */
+export type IntradayReporting = "market_hours" | "extended_hours" | "continuous";
@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JournalEntryType } from "./JournalEntryType";
+import type { JournalStatus } from "./JournalStatus";
+
+/**
+ * # Journal
+ * a journal, as returned when creating, getting or listing journals.
+ * Batch requests return one per entry.
+ */
+export type Journal = { id: string, entry_type: JournalEntryType, from_account: string, to_account: string, status: JournalStatus, symbol: string | null, qty: string | null, 
+/**
+ * the price the shares of a `JNLS` journal were valued at.
+ */
+price: string | null, 
+/**
+ * the cash moved, `0` for `JNLS` journals.
+ */
+net_amount: string, description: string | null, settle_date: string | null, system_date: string | null, currency: string | null, transmitter_name: string | null, transmitter_account_number: string | null, transmitter_address: string | null, transmitter_financial_institution: string | null, transmitter_timestamp: string | null, 
+/**
+ * why an entry of a batch was not journaled.
+ */
+error_message?: string, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # JournalEntryType
+ * `JNLC` journals move cash, `JNLS` journals move shares.
+ */
+export type JournalEntryType = "JNLC" | "JNLS";
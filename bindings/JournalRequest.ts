@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JournalEntryType } from "./JournalEntryType";
+
+/**
+ * # JournalRequest
+ * moves cash or shares between two accounts (POST /v1/journals).
+ * `JNLC` journals need an `amount`, `JNLS` journals a `symbol` and `qty`.
+ */
+export type JournalRequest = { from_account: string, to_account: string, entry_type: JournalEntryType, amount?: string, symbol?: string, qty?: string, description?: string, 
+/**
+ * the originator of the money, needed by the travel rule for cash journals over $3,000.
+ */
+transmitter_name?: string, transmitter_account_number?: string, transmitter_address?: string, transmitter_financial_institution?: string, transmitter_timestamp?: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # JournalStatus
+ */
+export type JournalStatus = "queued" | "sent_to_clearing" | "pending" | "executed" | "rejected" | "canceled" | "refused" | "correct" | "deleted";
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CryptoBar } from "./CryptoBar";
+
+/**
+ * The latest minute-aggregated historical bar data for each of the crypto symbols provided.
+ */
+export type LatestCryptoBars = { bars: { [key in string]?: CryptoBar }, };
@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OrderQueryStatus } from "./OrderQueryStatus";
+import type { OrderSide } from "./OrderSide";
+import type { SortDirection } from "./SortDirection";
+
+/**
+ * # ListOrdersQuery
+ * the query parameters for listing orders (GET /v2/orders).
+ * Unset fields are left out and fall back to the server defaults.
+ */
+export type ListOrdersQuery = { status?: OrderQueryStatus, 
+/**
+ * the maximum number of orders to return, up to 500.
+ */
+limit?: number, 
+/**
+ * only orders submitted after this time.
+ */
+after?: string, 
+/**
+ * only orders submitted until this time.
+ */
+until?: string, direction?: SortDirection, 
+/**
+ * if true, multi-leg orders are rolled up under the `legs` field of the primary order.
+ */
+nested?: boolean, symbols?: string, side?: OrderSide, };
@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AssetClass } from "./AssetClass";
+import type { PositionSide } from "./PositionSide";
+
+/**
+ * OpenPosition
+ * Describes the data layout of an open position on alpaca.
+ */
+export type OpenPosition = { asset_id: string, symbol: string, exchange: string, asset_class: AssetClass, avg_entry_price: string | null, qty: string, qty_available: string, side: PositionSide, market_value: string, cost_basis: string, unrealized_pl: string, unrealized_plpc: string, unrealized_intraday_pl: string, 
+/**
+ * sent as an empty string before the first trade of the day, which parses as zero.
+ */
+unrealized_intraday_plpc: string, current_price: string, lastday_price: string, change_today: string, asset_marginable: boolean, };
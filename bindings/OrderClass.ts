@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # OrderClass
+ * The class of an order. Defaults to `simple` on the server when omitted.
+ */
+export type OrderClass = "simple" | "bracket" | "oco" | "oto" | "mleg";
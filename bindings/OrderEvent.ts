@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # OrderEvent
+ * the lifecycle events Alpaca reports for an order on the `trade_updates` stream.
+ */
+export type OrderEvent = "pending_new" | "new" | "partial_fill" | "fill" | "done_for_day" | "canceled" | "expired" | "replaced" | "rejected" | "pending_cancel" | "pending_replace" | "stopped" | "suspended" | "calculated" | "order_cancel_rejected" | "order_replace_rejected" | "trade_bust" | "trade_correct" | string;
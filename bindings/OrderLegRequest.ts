@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OrderSide } from "./OrderSide";
+import type { PositionIntent } from "./PositionIntent";
+
+/**
+ * # OrderLegRequest
+ * A single leg of a multi-leg (`mleg`) options order.
+ */
+export type OrderLegRequest = { symbol: string, ratio_qty: string, side: OrderSide, position_intent?: PositionIntent, };
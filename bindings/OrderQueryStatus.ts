@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # OrderQueryStatus
+ * Which orders to return when listing orders. Defaults to `open` on the server.
+ */
+export type OrderQueryStatus = "open" | "closed" | "all";
@@ -0,0 +1,57 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OrderClass } from "./OrderClass";
+import type { OrderLegRequest } from "./OrderLegRequest";
+import type { OrderSide } from "./OrderSide";
+import type { OrderType } from "./OrderType";
+import type { PositionIntent } from "./PositionIntent";
+import type { StopLoss } from "./StopLoss";
+import type { TakeProfit } from "./TakeProfit";
+import type { TimeInForce } from "./TimeInForce";
+
+/**
+ * # OrderRequest
+ * the structure of data to send an order to Alpacas API.
+ * Only one of `qty` or `notional` should be set.
+ * Optional fields are left out of the payload when `None`.
+ */
+export type OrderRequest = { 
+/**
+ * required by every order class but `mleg`, whose legs carry the symbols.
+ */
+symbol?: string, qty?: string, 
+/**
+ * dollar amount to trade. Cannot be used together with `qty`.
+ */
+notional?: string, 
+/**
+ * required by every order class but `mleg`, whose legs carry the sides.
+ */
+side?: OrderSide, type: OrderType, time_in_force: TimeInForce, 
+/**
+ * required if type is `limit` or `stop_limit`.
+ */
+limit_price?: string, 
+/**
+ * required if type is `stop` or `stop_limit`.
+ */
+stop_price?: string, 
+/**
+ * used with `trailing_stop`. Only one of `trail_price` or `trail_percent` should be set.
+ */
+trail_price?: string, 
+/**
+ * used with `trailing_stop`. Only one of `trail_price` or `trail_percent` should be set.
+ */
+trail_percent?: string, 
+/**
+ * only works with `limit` orders and a `day` time in force.
+ */
+extended_hours?: boolean, 
+/**
+ * a unique identifier for the order, generated by the server if not provided.
+ */
+client_order_id?: string, order_class?: OrderClass, take_profit?: TakeProfit, stop_loss?: StopLoss, position_intent?: PositionIntent, 
+/**
+ * legs of a multi-leg options order. Only used with the `mleg` order class.
+ */
+legs?: Array<OrderLegRequest>, };
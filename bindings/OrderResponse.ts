@@ -0,0 +1,62 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AssetClass } from "./AssetClass";
+import type { JsonValue } from "./serde_json/JsonValue";
+import type { OrderSide } from "./OrderSide";
+import type { OrderStatus } from "./OrderStatus";
+import type { OrderType } from "./OrderType";
+import type { PositionIntent } from "./PositionIntent";
+import type { TimeInForce } from "./TimeInForce";
+
+/**
+ * # OrderResponse
+ * the structure of data in response to an order being sent.
+ */
+export type OrderResponse = { id: string, client_order_id: string, created_at: string, updated_at: string, submitted_at: string, filled_at: string | null, expired_at: string | null, canceled_at: string | null, failed_at: string | null, replaced_at: string | null, 
+/**
+ * The order ID that this order was replaced by
+ */
+replaced_by: string | null, 
+/**
+ * The order ID that this order replaces
+ */
+replaces: string | null, 
+/**
+ * Asset ID (For options this represents the option contract ID)
+ */
+asset_id: string, 
+/**
+ *Asset symbol
+ */
+symbol: string, asset_class: AssetClass, 
+/**
+ * Ordered notional amount. If entered, qty will be null. Can take up to 9 decimal points.
+ */
+notional: string | null, 
+/**
+ * Ordered quantity. If entered, notional will be null. Can take up to 9 decimal points.
+ */
+qty: string | null, 
+/**
+ * quantity filled in this order.
+ */
+filled_qty: string, 
+/**
+ * the average price this order filled at.
+ */
+filled_avg_price: string | null, 
+/**
+ * simple bracket oco oto
+ */
+order_class: string, type: OrderType, side: OrderSide, time_in_force: TimeInForce, limit_price: string | null, stop_price: string | null, status: OrderStatus, extended_hours: boolean, 
+/**
+ * the legs of a bracket, oco, oto or mleg order, when requested with `nested`.
+ */
+legs: Array<OrderResponse> | null, position_intent?: PositionIntent, 
+/**
+ * the ratio of a leg to the whole order, only set on the legs of mleg orders.
+ */
+ratio_qty?: string, 
+/**
+ * a deprecated copy of `type` that Alpaca still sends.
+ */
+order_type?: OrderType, trail_percent: JsonValue | null, trail_price: JsonValue | null, hwm: JsonValue | null, subtag: JsonValue | null, source: JsonValue | null, };
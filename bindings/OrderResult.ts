@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ApiError } from "./ApiError";
+import type { OrderResponse } from "./OrderResponse";
+
+/**
+ * # OrderResult
+ * the body of a single entry of a multi-status (207) response, such as closing all
+ * positions or canceling all orders. Successful entries hold the order,
+ * failed ones hold the error Alpaca returned for it.
+ * Prefer [`OrderResult::from_status`] when the status is known; deserializing without it
+ * tries an order first and falls back to an error.
+ */
+export type OrderResult = OrderResponse | ApiError;
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # Period
+ * The length of a portfolio history, e.g. `1W` or `3M`.
+ */
+export type Period = string;
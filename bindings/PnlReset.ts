@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # PnlReset
+ * Whether the baseline of intraday profit and loss resets every day.
+ */
+export type PnlReset = "per_day" | "no_reset";
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type PortfolioHistory = { timestamp: Array<number>, 
+/**
+ * points are `None` for intervals without data, e.g. when the market was closed.
+ */
+equity: Array<number | null>, profit_loss: Array<number | null>, profit_loss_pct: Array<number | null>, base_value: number, base_value_asof: string, timeframe: string, 
+/**
+ * cashflow amounts keyed by activity type (e.g. `DIV`, `FEE`), one entry per timestamp.
+ * Only present when requested with `cashflow_types`.
+ */
+cashflow?: { [key in string]?: Array<number | null> }, };
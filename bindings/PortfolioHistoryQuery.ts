@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IntradayReporting } from "./IntradayReporting";
+import type { Period } from "./Period";
+import type { PnlReset } from "./PnlReset";
+import type { Timeframe } from "./Timeframe";
+
+/**
+ * # PortfolioHistoryQuery
+ * the query parameters for GET /v2/account/portfolio/history.
+ * Unset fields are left out and fall back to the server defaults.
+ */
+export type PortfolioHistoryQuery = { period?: Period, timeframe?: Timeframe, 
+/**
+ * the date the history ends on. Superseded by `end`.
+ */
+date_end?: string, start?: string, end?: string, intraday_reporting?: IntradayReporting, pnl_reset?: PnlReset, 
+/**
+ * the activity types to include in `cashflow`, e.g. `DIV`, or `ALL`/`NONE`.
+ */
+cashflow_types?: string, };
@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TimeInForce } from "./TimeInForce";
+
+/**
+ * # ReplaceOrderRequest
+ * the structure of data to replace (PATCH) an open order.
+ * Every field is optional and only the ones that are set are sent.
+ */
+export type ReplaceOrderRequest = { qty?: string, time_in_force?: TimeInForce, limit_price?: string, stop_price?: string, 
+/**
+ * the new value of the `trail_price` or `trail_percent` of a trailing stop order.
+ */
+trail?: string, 
+/**
+ * a unique identifier for the new order.
+ */
+client_order_id?: string, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # ReverseBatchJournalEntry
+ * one sending account of a [`ReverseBatchJournalRequest`].
+ */
+export type ReverseBatchJournalEntry = { from_account: string, amount: string, description?: string, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JournalEntryType } from "./JournalEntryType";
+import type { ReverseBatchJournalEntry } from "./ReverseBatchJournalEntry";
+
+/**
+ * # ReverseBatchJournalRequest
+ * moves cash from many accounts into one, e.g. to collect fees (POST /v1/journals/reverse_batch).
+ * Only `JNLC` journals can be reversed in a batch.
+ */
+export type ReverseBatchJournalRequest = { entry_type: JournalEntryType, to_account: string, entries: Array<ReverseBatchJournalEntry>, };
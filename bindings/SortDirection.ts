@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # SortDirection
+ * The chronological order of the returned orders. Defaults to `desc` on the server.
+ */
+export type SortDirection = "asc" | "desc";
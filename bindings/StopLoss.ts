@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # StopLoss
+ * The stop loss leg of an advanced (bracket, oco, oto) order.
+ * * `stop_price`: the price that triggers the stop loss.
+ * * `limit_price`: if given, the stop loss becomes a stop limit order.
+ */
+export type StopLoss = { stop_price: string, limit_price?: string, };
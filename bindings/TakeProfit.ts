@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # TakeProfit
+ * The take profit leg of an advanced (bracket, oco, oto) order.
+ */
+export type TakeProfit = { limit_price: string, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # Timeframe
+ * The resolution of the points in a portfolio history.
+ */
+export type Timeframe = "1Min" | "5Min" | "15Min" | "1H" | "1D";
@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OrderEvent } from "./OrderEvent";
+import type { OrderResponse } from "./OrderResponse";
+
+/**
+ * # TradeUpdate
+ * an order update received on the `trade_updates` stream.
+ * `price`, `qty` and `execution_id` are only present for `fill` and `partial_fill` events,
+ * and for `trade_bust` and `trade_correct` events naming the execution they change.
+ */
+export type TradeUpdate = { event: OrderEvent, execution_id?: string, order: OrderResponse, timestamp: string | null, 
+/**
+ * the price of this fill.
+ */
+price?: string, 
+/**
+ * the quantity of this fill.
+ */
+qty?: string, 
+/**
+ * the size of the position after this fill.
+ */
+position_qty?: string, };
@@ -0,0 +1,31 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FeePaymentMethod } from "./FeePaymentMethod";
+import type { TransferDirection } from "./TransferDirection";
+import type { TransferStatus } from "./TransferStatus";
+import type { TransferType } from "./TransferType";
+
+/**
+ * # Transfer
+ * a deposit or withdrawal, as returned when creating or listing transfers.
+ */
+export type Transfer = { id: string, account_id: string, 
+/**
+ * the ACH relationship of `ach` transfers.
+ */
+relationship_id: string | null, 
+/**
+ * the bank of `wire` transfers.
+ */
+bank_id: string | null, type: TransferType, status: TransferStatus, 
+/**
+ * why the transfer was rejected or returned.
+ */
+reason: string | null, 
+/**
+ * the amount that moves, i.e. `requested_amount` less any fee the user pays.
+ */
+amount: string, requested_amount: string | null, fee: string | null, fee_payment_method: FeePaymentMethod | null, direction: TransferDirection, additional_information: string | null, created_at: string, updated_at: string, expires_at: string | null, 
+/**
+ * deposited money cannot be withdrawn before this.
+ */
+hold_until: string | null, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # TransferDirection
+ * `INCOMING` deposits into the account, `OUTGOING` withdraws from it.
+ */
+export type TransferDirection = "INCOMING" | "OUTGOING";
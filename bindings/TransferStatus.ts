@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # TransferStatus
+ */
+export type TransferStatus = "QUEUED" | "APPROVAL_PENDING" | "PENDING" | "SENT_TO_CLEARING" | "REJECTED" | "CANCELED" | "APPROVED" | "COMPLETE" | "RETURNED" | "UNKNOWN";
@@ -0,0 +1,21 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TransferStatus } from "./TransferStatus";
+
+/**
+ * # TransferStatusEvent
+ * an event of the transfer status stream (GET /v1/events/transfers/status),
+ * sent whenever a transfer's status changes.
+ */
+export type TransferStatusEvent = { account_id: string, transfer_id: string, at: string, 
+/**
+ * the id to resume the stream from with `since_id`.
+ */
+event_id: number | null, 
+/**
+ * the id to resume the stream from with `since_ulid`.
+ */
+event_ulid: string | null, 
+/**
+ * `None` for the event of a new transfer.
+ */
+status_from: TransferStatus | null, status_to: TransferStatus | null, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # TransferType
+ * `ach` transfers go through an [`AchRelationship`], `wire` transfers through a
+ * [`BankRelationship`].
+ */
+export type TransferType = "ach" | "wire";
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * # TrustedContact
+ * Someone Alpaca may contact about the account, e.g. when they suspect fraud.
+ */
+export type TrustedContact = { given_name: string, family_name: string, 
+/**
+ * at least one of `email_address`, `phone_number` or a full address is required.
+ */
+email_address?: string, phone_number?: string, street_address?: Array<string>, city?: string, state?: string, postal_code?: string, country?: string, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Contact } from "./Contact";
+import type { Disclosures } from "./Disclosures";
+import type { Identity } from "./Identity";
+import type { TrustedContact } from "./TrustedContact";
+
+/**
+ * # UpdateAccountRequest
+ * updates an account (PATCH /v1/accounts/{account_id}).
+ * Sections left as `None` are unchanged.
+ */
+export type UpdateAccountRequest = { contact?: Contact, identity?: Identity, disclosures?: Disclosures, trusted_contact?: TrustedContact, };
@@ -0,0 +1,209 @@
+//! checks the TypeScript bindings in `bindings/` against the types they are generated from.
+//! Regenerate them with `UPDATE_BINDINGS=1 cargo test --features ts bindings`.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+
+use ts_rs::{ExportError, TS};
+
+use crate::broker::accounts::{
+    AccountDocument, AccountStatus, AccountStatusEvent, AccountType, Agreement, AgreementType,
+    BrokerAccount, Contact, CreateAccountRequest, DisclosureContext, DisclosureContextType,
+    Disclosures, EmploymentStatus, FundingSource, Identity, TrustedContact, UpdateAccountRequest,
+};
+use crate::broker::funding::{
+    AchRelationship, AchRelationshipStatus, BankAccountType, BankCodeType, BankRelationship,
+    BankRelationshipStatus, CreateAchRelationshipRequest, CreateBankRequest, CreateTransferRequest,
+    FeePaymentMethod, Transfer, TransferDirection, TransferStatus, TransferStatusEvent,
+    TransferTiming, TransferType,
+};
+use crate::broker::journals::{
+    BatchJournalEntry, BatchJournalRequest, Journal, JournalEntryType, JournalRequest,
+    JournalStatus, ReverseBatchJournalEntry, ReverseBatchJournalRequest,
+};
+use crate::errors::{AlpacaError, ApiError, ErrorMessage};
+use crate::market_data::crypto::{CryptoBar, LatestCryptoBars};
+use crate::market_data::stock::{Bar, Bars, LatestTrades, Trade};
+use crate::orders::{
+    AssetClass, CancelAllOrdersResponse, ListOrdersQuery, OrderClass, OrderEvent, OrderLegRequest,
+    OrderQueryStatus, OrderRequest, OrderResponse, OrderResult, OrderSide, OrderStatus, OrderType,
+    PositionIntent, ReplaceOrderRequest, SortDirection, StopLoss, TakeProfit, TimeInForce,
+    TradeUpdate,
+};
+use crate::positions::{
+    CloseAllPositionsRequest, CloseAllPositionsResponse, ClosePositionRequest, OpenPosition,
+    PositionSide,
+};
+use crate::trading::portfolio_history::{HistoryFrame, PortfolioHistory, Timeframe};
+use crate::trading::portfolio_history_query::{
+    IntradayReporting, Period, PnlReset, PortfolioHistoryQuery,
+};
+
+/// exports every type, and the types they depend on, into `out_dir`.
+fn export_all(out_dir: &Path) -> Result<(), ExportError> {
+    AccountStatus::export_all_to(out_dir)?;
+    AccountType::export_all_to(out_dir)?;
+    FundingSource::export_all_to(out_dir)?;
+    EmploymentStatus::export_all_to(out_dir)?;
+    AgreementType::export_all_to(out_dir)?;
+    DisclosureContextType::export_all_to(out_dir)?;
+    Contact::export_all_to(out_dir)?;
+    Identity::export_all_to(out_dir)?;
+    DisclosureContext::export_all_to(out_dir)?;
+    Disclosures::export_all_to(out_dir)?;
+    Agreement::export_all_to(out_dir)?;
+    TrustedContact::export_all_to(out_dir)?;
+    AccountDocument::export_all_to(out_dir)?;
+    CreateAccountRequest::export_all_to(out_dir)?;
+    UpdateAccountRequest::export_all_to(out_dir)?;
+    BrokerAccount::export_all_to(out_dir)?;
+    AccountStatusEvent::export_all_to(out_dir)?;
+
+    BankAccountType::export_all_to(out_dir)?;
+    AchRelationshipStatus::export_all_to(out_dir)?;
+    CreateAchRelationshipRequest::export_all_to(out_dir)?;
+    AchRelationship::export_all_to(out_dir)?;
+    BankCodeType::export_all_to(out_dir)?;
+    BankRelationshipStatus::export_all_to(out_dir)?;
+    CreateBankRequest::export_all_to(out_dir)?;
+    BankRelationship::export_all_to(out_dir)?;
+    TransferType::export_all_to(out_dir)?;
+    TransferDirection::export_all_to(out_dir)?;
+    TransferTiming::export_all_to(out_dir)?;
+    FeePaymentMethod::export_all_to(out_dir)?;
+    TransferStatus::export_all_to(out_dir)?;
+    CreateTransferRequest::export_all_to(out_dir)?;
+    Transfer::export_all_to(out_dir)?;
+    TransferStatusEvent::export_all_to(out_dir)?;
+
+    JournalEntryType::export_all_to(out_dir)?;
+    JournalStatus::export_all_to(out_dir)?;
+    JournalRequest::export_all_to(out_dir)?;
+    BatchJournalEntry::export_all_to(out_dir)?;
+    BatchJournalRequest::export_all_to(out_dir)?;
+    ReverseBatchJournalEntry::export_all_to(out_dir)?;
+    ReverseBatchJournalRequest::export_all_to(out_dir)?;
+    Journal::export_all_to(out_dir)?;
+
+    ErrorMessage::export_all_to(out_dir)?;
+    ApiError::export_all_to(out_dir)?;
+    AlpacaError::export_all_to(out_dir)?;
+
+    CryptoBar::export_all_to(out_dir)?;
+    LatestCryptoBars::export_all_to(out_dir)?;
+    Trade::export_all_to(out_dir)?;
+    LatestTrades::export_all_to(out_dir)?;
+    Bar::export_all_to(out_dir)?;
+    Bars::export_all_to(out_dir)?;
+
+    AssetClass::export_all_to(out_dir)?;
+    OrderSide::export_all_to(out_dir)?;
+    OrderType::export_all_to(out_dir)?;
+    TimeInForce::export_all_to(out_dir)?;
+    OrderClass::export_all_to(out_dir)?;
+    PositionIntent::export_all_to(out_dir)?;
+    TakeProfit::export_all_to(out_dir)?;
+    StopLoss::export_all_to(out_dir)?;
+    OrderLegRequest::export_all_to(out_dir)?;
+    OrderRequest::export_all_to(out_dir)?;
+    ReplaceOrderRequest::export_all_to(out_dir)?;
+    OrderResponse::export_all_to(out_dir)?;
+    OrderResult::export_all_to(out_dir)?;
+    OrderStatus::export_all_to(out_dir)?;
+    OrderEvent::export_all_to(out_dir)?;
+    TradeUpdate::export_all_to(out_dir)?;
+    OrderQueryStatus::export_all_to(out_dir)?;
+    SortDirection::export_all_to(out_dir)?;
+    ListOrdersQuery::export_all_to(out_dir)?;
+    CancelAllOrdersResponse::export_all_to(out_dir)?;
+
+    PositionSide::export_all_to(out_dir)?;
+    OpenPosition::export_all_to(out_dir)?;
+    ClosePositionRequest::export_all_to(out_dir)?;
+    CloseAllPositionsRequest::export_all_to(out_dir)?;
+    CloseAllPositionsResponse::export_all_to(out_dir)?;
+
+    PortfolioHistory::export_all_to(out_dir)?;
+    Timeframe::export_all_to(out_dir)?;
+    HistoryFrame::export_all_to(out_dir)?;
+    Period::export_all_to(out_dir)?;
+    IntradayReporting::export_all_to(out_dir)?;
+    PnlReset::export_all_to(out_dir)?;
+    PortfolioHistoryQuery::export_all_to(out_dir)?;
+    Ok(())
+}
+
+/// the contents of every `.ts` file in `dir`, keyed by file name.
+fn read_bindings(dir: &Path) -> BTreeMap<String, String> {
+    let entries = fs::read_dir(dir).expect("failed to read bindings directory");
+    entries
+        .map(|entry| entry.expect("failed to read bindings entry").path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "ts"))
+        .map(|path| {
+            let name = path.file_name().expect("binding without a name");
+            let contents = fs::read_to_string(&path).expect("failed to read binding");
+            (name.to_string_lossy().into_owned(), contents)
+        })
+        .collect()
+}
+
+#[test]
+fn bindings_are_up_to_date() {
+    let checked_in = Path::new(env!("CARGO_MANIFEST_DIR")).join("bindings");
+    if std::env::var_os("UPDATE_BINDINGS").is_some() {
+        if checked_in.exists() {
+            fs::remove_dir_all(&checked_in).expect("failed to clear bindings");
+        }
+        export_all(&checked_in).expect("failed to export bindings");
+    }
+
+    let out_dir =
+        std::env::temp_dir().join(format!("alpaca_types_bindings_{}", std::process::id()));
+    export_all(&out_dir).expect("failed to export bindings");
+    let generated = read_bindings(&out_dir);
+    fs::remove_dir_all(&out_dir).expect("failed to remove generated bindings");
+
+    let existing = read_bindings(&checked_in);
+    let stale: BTreeSet<&String> = generated
+        .keys()
+        .chain(existing.keys())
+        .filter(|name| generated.get(*name) != existing.get(*name))
+        .collect();
+    assert!(
+        stale.is_empty(),
+        "bindings are out of date, regenerate them with \
+         `UPDATE_BINDINGS=1 cargo test --features ts bindings`: {stale:?}"
+    );
+}
+
+#[test]
+fn bindings_use_wire_types() {
+    let order = OrderResponse::decl();
+    assert!(order.contains("created_at: string,"), "{order}");
+    assert!(order.contains("filled_at: string | null,"), "{order}");
+    assert!(order.contains("qty: string | null,"), "{order}");
+    assert!(
+        order.contains("position_intent?: PositionIntent,"),
+        "{order}"
+    );
+
+    let error = ErrorMessage::decl();
+    assert!(error.contains("code: number,"), "{error}");
+
+    // JSON.parse produces numbers, never bigints.
+    let history = PortfolioHistory::decl();
+    assert!(history.contains("timestamp: Array<number>,"), "{history}");
+    let bar = Bar::decl();
+    assert!(bar.contains("v: number, n: number,"), "{bar}");
+
+    let position = OpenPosition::decl();
+    assert!(position.contains("market_value: string,"), "{position}");
+    assert!(
+        position.contains("avg_entry_price: string | null,"),
+        "{position}"
+    );
+
+    assert_eq!(AlpacaError::inline(), ApiError::inline());
+    assert_eq!(Period::inline(), "string");
+}
@@ -0,0 +1,784 @@
+use serde::{Deserialize, Serialize};
+use time::serde::rfc3339;
+use time::{Date, OffsetDateTime};
+use uuid::Uuid;
+
+use crate::orders::AssetClass;
+use crate::{
+    date_from_opt_string, empty_string_is_none, f64_from_opt_string, serialize_date,
+    serialize_opt_qty,
+};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "ts")]
+use ts_rs::TS;
+
+/// # AccountStatus
+/// Where an account is in onboarding. Accounts can trade once they are `ACTIVE`.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AccountStatus {
+    Inactive,
+    Onboarding,
+    Submitted,
+    SubmissionFailed,
+    /// more information or documents are needed from the user.
+    ActionRequired,
+    Edited,
+    AccountUpdated,
+    ApprovalPending,
+    Approved,
+    Rejected,
+    Active,
+    Disabled,
+    AccountClosed,
+    /// a status this crate does not model yet, so new statuses do not fail a whole event.
+    #[serde(other)]
+    Unknown,
+}
+
+/// # AccountType
+/// The kind of brokerage account. Defaults to `trading` on the server.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum AccountType {
+    Trading,
+    Custodial,
+    Ira,
+}
+
+/// # FundingSource
+/// Where the money in an account comes from.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum FundingSource {
+    EmploymentIncome,
+    Investments,
+    Inheritance,
+    BusinessIncome,
+    Savings,
+    Family,
+}
+
+/// # EmploymentStatus
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum EmploymentStatus {
+    Unemployed,
+    Employed,
+    Student,
+    Retired,
+}
+
+/// # AgreementType
+/// The agreements a user signs. Every account needs the customer agreement,
+/// margin, crypto and options trading need their own.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum AgreementType {
+    AccountAgreement,
+    CustomerAgreement,
+    MarginAgreement,
+    CryptoAgreement,
+    OptionsAgreement,
+}
+
+/// # DisclosureContextType
+/// What a [`DisclosureContext`] explains.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DisclosureContextType {
+    /// the user is affiliated with an exchange or FINRA member firm.
+    AffiliateFirm,
+    /// the user is a control person of a publicly traded company.
+    ControlledFirm,
+    /// an immediate family member is politically exposed.
+    ImmediateFamilyExposed,
+}
+
+/// # Contact
+/// How to reach the account holder.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct Contact {
+    pub email_address: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub phone_number: Option<String>,
+    /// one entry per line of the address.
+    #[serde(default)]
+    pub street_address: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub unit: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub city: Option<String>,
+    /// required for US addresses.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub state: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub postal_code: Option<String>,
+    /// ISO 3166-1 alpha-3 code, e.g. `USA`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub country: Option<String>,
+}
+
+/// # Identity
+/// Who the account holder is. Alpaca leaves `tax_id` out of responses.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct Identity {
+    pub given_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub middle_name: Option<String>,
+    pub family_name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_date",
+        deserialize_with = "date_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::FullDate>")
+    )]
+    pub date_of_birth: Option<Date>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub tax_id: Option<String>,
+    /// e.g. `USA_SSN`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub tax_id_type: Option<String>,
+    /// ISO 3166-1 alpha-3 code, e.g. `USA`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub country_of_citizenship: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub country_of_birth: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub country_of_tax_residence: Option<String>,
+    #[serde(default)]
+    pub funding_source: Vec<FundingSource>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub annual_income_min: Option<f64>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub annual_income_max: Option<f64>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub liquid_net_worth_min: Option<f64>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub liquid_net_worth_max: Option<f64>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub total_net_worth_min: Option<f64>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub total_net_worth_max: Option<f64>,
+    /// only for users without US citizenship or permanent residence, e.g. `H1B`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub visa_type: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_date",
+        deserialize_with = "date_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::FullDate>")
+    )]
+    pub visa_expiration_date: Option<Date>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_date",
+        deserialize_with = "date_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::FullDate>")
+    )]
+    pub date_of_departure_from_usa: Option<Date>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub permanent_resident: Option<bool>,
+}
+
+/// # DisclosureContext
+/// The details behind a disclosure, e.g. the firm a user is affiliated with.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct DisclosureContext {
+    pub context_type: DisclosureContextType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub company_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub company_street_address: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub company_city: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub company_state: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub company_country: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub company_compliance_email: Option<String>,
+    /// the exposed family member, for `IMMEDIATE_FAMILY_EXPOSED`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub given_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub family_name: Option<String>,
+}
+
+/// # Disclosures
+/// The regulatory disclosures of the account holder.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct Disclosures {
+    #[serde(default)]
+    pub is_control_person: bool,
+    #[serde(default)]
+    pub is_affiliated_exchange_or_finra: bool,
+    #[serde(default)]
+    pub is_politically_exposed: bool,
+    #[serde(default)]
+    pub immediate_family_exposed: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub employment_status: Option<EmploymentStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub employer_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub employer_address: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub employment_position: Option<String>,
+    /// required when any of the flags above is true.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "ts", ts(as = "Option<Vec<DisclosureContext>>", optional))]
+    pub context: Vec<DisclosureContext>,
+}
+
+/// # Agreement
+/// An agreement the user signed, with where and when they signed it.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct Agreement {
+    pub agreement: AgreementType,
+    #[serde(with = "rfc3339")]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::schema::DateTime"))]
+    pub signed_at: OffsetDateTime,
+    pub ip_address: String,
+    /// the revision of the agreement that was signed, e.g. `19.2022.02`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub revision: Option<String>,
+}
+
+/// # TrustedContact
+/// Someone Alpaca may contact about the account, e.g. when they suspect fraud.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct TrustedContact {
+    pub given_name: String,
+    pub family_name: String,
+    /// at least one of `email_address`, `phone_number` or a full address is required.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub email_address: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub phone_number: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub street_address: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub city: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub state: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub postal_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub country: Option<String>,
+}
+
+/// # AccountDocument
+/// A document uploaded for an account, e.g. a photo of a passport.
+/// `content` is only sent when uploading, `id` and `created_at` only come back in responses.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct AccountDocument {
+    /// e.g. `identity_verification`.
+    pub document_type: String,
+    /// e.g. `passport`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub document_sub_type: Option<String>,
+    /// the base64 encoded file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub content: Option<String>,
+    /// e.g. `image/jpeg`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub mime_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub id: Option<Uuid>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "rfc3339::option"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::DateTime>")
+    )]
+    pub created_at: Option<OffsetDateTime>,
+}
+
+/// # CreateAccountRequest
+/// opens an account for an end user (POST /v1/accounts).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct CreateAccountRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub account_type: Option<AccountType>,
+    pub contact: Contact,
+    pub identity: Identity,
+    pub disclosures: Disclosures,
+    pub agreements: Vec<Agreement>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "ts", ts(as = "Option<Vec<AccountDocument>>", optional))]
+    pub documents: Vec<AccountDocument>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub trusted_contact: Option<TrustedContact>,
+    /// the asset classes the account can trade, `us_equity` when left out.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub enabled_assets: Option<Vec<AssetClass>>,
+}
+
+/// # UpdateAccountRequest
+/// updates an account (PATCH /v1/accounts/{account_id}).
+/// Sections left as `None` are unchanged.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct UpdateAccountRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub contact: Option<Contact>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub identity: Option<Identity>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub disclosures: Option<Disclosures>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub trusted_contact: Option<TrustedContact>,
+}
+
+/// # BrokerAccount
+/// an end user's account, as returned when creating, getting or listing accounts.
+/// Listing accounts leaves out most of the identity and the documents.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+pub struct BrokerAccount {
+    pub id: Uuid,
+    pub account_number: String,
+    pub status: AccountStatus,
+    /// the status of crypto trading, which is approved separately.
+    #[serde(default)]
+    pub crypto_status: Option<AccountStatus>,
+    pub currency: String,
+    #[serde(
+        default,
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(type = "string | null"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub last_equity: Option<f64>,
+    #[serde(with = "rfc3339")]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::schema::DateTime"))]
+    pub created_at: OffsetDateTime,
+    #[serde(default)]
+    pub account_type: Option<AccountType>,
+    #[serde(default)]
+    pub contact: Option<Contact>,
+    #[serde(default)]
+    pub identity: Option<Identity>,
+    #[serde(default)]
+    pub disclosures: Option<Disclosures>,
+    #[serde(default)]
+    pub agreements: Vec<Agreement>,
+    #[serde(default)]
+    pub documents: Vec<AccountDocument>,
+    #[serde(default)]
+    pub trusted_contact: Option<TrustedContact>,
+    #[serde(default)]
+    pub enabled_assets: Vec<AssetClass>,
+    /// fields Alpaca sent that this type does not know yet.
+    #[cfg(feature = "extra_fields")]
+    #[cfg_attr(not(feature = "deny_unknown_fields"), serde(flatten))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(skip))]
+    #[cfg_attr(feature = "ts", ts(skip))]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
+}
+
+/// # AccountStatusEvent
+/// an event of the account status stream (GET /v1/events/accounts/status),
+/// sent whenever an account's status or crypto status changes.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+pub struct AccountStatusEvent {
+    pub account_id: Uuid,
+    #[serde(default)]
+    pub account_number: String,
+    #[serde(with = "rfc3339")]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::schema::DateTime"))]
+    pub at: OffsetDateTime,
+    /// the id to resume the stream from with `since_id`.
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(type = "number | null"))]
+    pub event_id: Option<u64>,
+    /// the id to resume the stream from with `since_ulid`.
+    #[serde(default)]
+    pub event_ulid: Option<String>,
+    #[serde(default, deserialize_with = "empty_string_is_none")]
+    pub status_from: Option<AccountStatus>,
+    #[serde(default, deserialize_with = "empty_string_is_none")]
+    pub status_to: Option<AccountStatus>,
+    #[serde(default, deserialize_with = "empty_string_is_none")]
+    pub crypto_status_from: Option<AccountStatus>,
+    #[serde(default, deserialize_with = "empty_string_is_none")]
+    pub crypto_status_to: Option<AccountStatus>,
+    #[serde(default)]
+    pub reason: Option<String>,
+    /// the outcome of the identity checks, when they ran.
+    #[serde(default)]
+    pub kyc_results: Option<serde_json::Value>,
+    /// fields Alpaca sent that this type does not know yet.
+    #[cfg(feature = "extra_fields")]
+    #[cfg_attr(not(feature = "deny_unknown_fields"), serde(flatten))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(skip))]
+    #[cfg_attr(feature = "ts", ts(skip))]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use time::macros::{date, datetime};
+
+    use super::{
+        AccountStatus, AccountStatusEvent, Agreement, AgreementType, BrokerAccount, Contact,
+        CreateAccountRequest, Disclosures, FundingSource, Identity, UpdateAccountRequest,
+    };
+    use crate::orders::AssetClass;
+
+    #[test]
+    fn create_account_request_serializes() {
+        let request = CreateAccountRequest {
+            account_type: None,
+            contact: Contact {
+                email_address: "cool_alpaca@example.com".to_string(),
+                phone_number: Some("555-666-7788".to_string()),
+                street_address: vec!["20 N San Mateo Dr".to_string()],
+                city: Some("San Mateo".to_string()),
+                state: Some("CA".to_string()),
+                postal_code: Some("94401".to_string()),
+                country: Some("USA".to_string()),
+                ..Default::default()
+            },
+            identity: Identity {
+                given_name: "John".to_string(),
+                family_name: "Doe".to_string(),
+                date_of_birth: Some(date!(1990 - 01 - 01)),
+                tax_id: Some("666-55-4321".to_string()),
+                tax_id_type: Some("USA_SSN".to_string()),
+                country_of_tax_residence: Some("USA".to_string()),
+                funding_source: vec![FundingSource::EmploymentIncome],
+                annual_income_min: Some(30000.0),
+                annual_income_max: Some(50000.0),
+                ..Default::default()
+            },
+            disclosures: Disclosures::default(),
+            agreements: vec![Agreement {
+                agreement: AgreementType::CustomerAgreement,
+                signed_at: datetime!(2024-07-24 13:30 UTC),
+                ip_address: "185.13.21.99".to_string(),
+                revision: None,
+            }],
+            documents: vec![],
+            trusted_contact: None,
+            enabled_assets: Some(vec![AssetClass::UsEquity, AssetClass::Crypto]),
+        };
+
+        let wanted = json!({
+            "contact": {
+                "email_address": "cool_alpaca@example.com",
+                "phone_number": "555-666-7788",
+                "street_address": ["20 N San Mateo Dr"],
+                "city": "San Mateo",
+                "state": "CA",
+                "postal_code": "94401",
+                "country": "USA"
+            },
+            "identity": {
+                "given_name": "John",
+                "family_name": "Doe",
+                "date_of_birth": "1990-01-01",
+                "tax_id": "666-55-4321",
+                "tax_id_type": "USA_SSN",
+                "country_of_tax_residence": "USA",
+                "funding_source": ["employment_income"],
+                "annual_income_min": "30000",
+                "annual_income_max": "50000"
+            },
+            "disclosures": {
+                "is_control_person": false,
+                "is_affiliated_exchange_or_finra": false,
+                "is_politically_exposed": false,
+                "immediate_family_exposed": false
+            },
+            "agreements": [{
+                "agreement": "customer_agreement",
+                "signed_at": "2024-07-24T13:30:00Z",
+                "ip_address": "185.13.21.99"
+            }],
+            "enabled_assets": ["us_equity", "crypto"]
+        });
+        assert_eq!(
+            serde_json::to_value(&request).expect("failed to serialize request"),
+            wanted
+        );
+
+        let update = UpdateAccountRequest {
+            contact: Some(request.contact),
+            ..Default::default()
+        };
+        let update = serde_json::to_value(&update).expect("failed to serialize update");
+        assert_eq!(update.as_object().map(|update| update.len()), Some(1));
+    }
+
+    #[test]
+    fn broker_account_parses() {
+        let payload = r#"
+        {
+            "id": "0d969814-40d6-4b2b-99ac-2e37427f692f",
+            "account_number": "682455281",
+            "status": "APPROVED",
+            "crypto_status": "INACTIVE",
+            "currency": "USD",
+            "last_equity": "0",
+            "created_at": "2024-07-24T13:30:12.347219Z",
+            "contact": {
+                "email_address": "cool_alpaca@example.com",
+                "phone_number": "555-666-7788",
+                "street_address": ["20 N San Mateo Dr"],
+                "city": "San Mateo",
+                "state": "CA",
+                "postal_code": "94401"
+            },
+            "identity": {
+                "given_name": "John",
+                "family_name": "Doe",
+                "date_of_birth": "1990-01-01",
+                "country_of_tax_residence": "USA",
+                "funding_source": ["employment_income"]
+            },
+            "disclosures": {
+                "is_control_person": false,
+                "is_affiliated_exchange_or_finra": false,
+                "is_politically_exposed": false,
+                "immediate_family_exposed": false,
+                "employment_status": "employed",
+                "employer_name": "Alpaca"
+            },
+            "agreements": [
+                {
+                    "agreement": "customer_agreement",
+                    "signed_at": "2024-07-24T13:30:11Z",
+                    "ip_address": "185.13.21.99",
+                    "revision": "19.2022.02"
+                }
+            ],
+            "documents": [
+                {
+                    "document_type": "identity_verification",
+                    "document_sub_type": "passport",
+                    "id": "d5e1c9ad-8c3b-4e37-8a4b-0de0d1f8dd2d",
+                    "created_at": "2024-07-24T13:30:12Z"
+                }
+            ],
+            "trusted_contact": {
+                "given_name": "Jane",
+                "family_name": "Doe",
+                "email_address": "jane.doe@example.com"
+            },
+            "account_type": "trading",
+            "enabled_assets": ["us_equity"]
+        }
+        "#;
+
+        let account =
+            serde_json::from_str::<BrokerAccount>(payload).expect("failed to parse account");
+        assert_eq!(account.status, AccountStatus::Approved);
+        assert_eq!(account.last_equity, Some(0.0));
+        assert_eq!(
+            account.identity.and_then(|identity| identity.date_of_birth),
+            Some(date!(1990 - 01 - 01))
+        );
+        assert_eq!(account.documents.len(), 1);
+        assert_eq!(account.enabled_assets, vec![AssetClass::UsEquity]);
+    }
+
+    #[test]
+    fn account_status_event_parses() {
+        let payload = r#"
+        {
+            "account_id": "0d969814-40d6-4b2b-99ac-2e37427f692f",
+            "account_number": "682455281",
+            "at": "2024-07-24T13:31:02.195418Z",
+            "event_id": 4815,
+            "event_ulid": "01J3JN7A0ZK2M3XG8N5QAW8V6Y",
+            "status_from": "SUBMITTED",
+            "status_to": "APPROVED",
+            "crypto_status_from": "",
+            "crypto_status_to": "",
+            "reason": ""
+        }
+        "#;
+
+        let event = serde_json::from_str::<AccountStatusEvent>(payload)
+            .expect("failed to parse account status event");
+        assert_eq!(event.status_from, Some(AccountStatus::Submitted));
+        assert_eq!(event.status_to, Some(AccountStatus::Approved));
+        assert_eq!(event.crypto_status_to, None);
+        assert_eq!(event.event_id, Some(4815));
+
+        let payload = payload.replace("\"APPROVED\"", "\"UNDER_REVIEW\"");
+        let event = serde_json::from_str::<AccountStatusEvent>(&payload)
+            .expect("failed to parse unknown account status");
+        assert_eq!(event.status_to, Some(AccountStatus::Unknown));
+    }
+}
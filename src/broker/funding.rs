@@ -0,0 +1,521 @@
+use serde::{Deserialize, Serialize};
+use time::serde::rfc3339;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{
+    empty_string_is_none, f64_from_opt_string, serialize_opt_qty, serialize_qty, string_to_f64,
+};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "ts")]
+use ts_rs::TS;
+
+/// # BankAccountType
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BankAccountType {
+    Checking,
+    Savings,
+}
+
+/// # AchRelationshipStatus
+/// ACH transfers can use a relationship once it is `APPROVED`.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AchRelationshipStatus {
+    Queued,
+    Approved,
+    Pending,
+    CancelRequested,
+    Canceled,
+}
+
+/// # CreateAchRelationshipRequest
+/// links a bank account for ACH transfers (POST /v1/accounts/{account_id}/ach_relationships).
+/// Either pass the bank details or a Plaid `processor_token`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct CreateAchRelationshipRequest {
+    pub account_owner_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub bank_account_type: Option<BankAccountType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub bank_account_number: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub bank_routing_number: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub nickname: Option<String>,
+    /// a Plaid processor token, used instead of the bank details.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub processor_token: Option<String>,
+}
+
+/// # AchRelationship
+/// a bank account linked to an account for ACH transfers.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+pub struct AchRelationship {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    #[serde(with = "rfc3339")]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::schema::DateTime"))]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "rfc3339")]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::schema::DateTime"))]
+    pub updated_at: OffsetDateTime,
+    pub status: AchRelationshipStatus,
+    pub account_owner_name: String,
+    pub bank_account_type: BankAccountType,
+    pub bank_account_number: String,
+    pub bank_routing_number: String,
+    #[serde(default)]
+    pub nickname: Option<String>,
+    /// fields Alpaca sent that this type does not know yet.
+    #[cfg(feature = "extra_fields")]
+    #[cfg_attr(not(feature = "deny_unknown_fields"), serde(flatten))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(skip))]
+    #[cfg_attr(feature = "ts", ts(skip))]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
+}
+
+/// # BankCodeType
+/// How a bank is identified, `ABA` routing numbers for US banks and `BIC` (SWIFT) otherwise.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BankCodeType {
+    Aba,
+    Bic,
+}
+
+/// # BankRelationshipStatus
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BankRelationshipStatus {
+    Queued,
+    SentToClearing,
+    Approved,
+    Canceled,
+}
+
+/// # CreateBankRequest
+/// links a bank account for wire transfers (POST /v1/accounts/{account_id}/recipient_banks).
+/// The address is only needed for international banks.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct CreateBankRequest {
+    /// the name of the bank.
+    pub name: String,
+    pub bank_code: String,
+    pub bank_code_type: BankCodeType,
+    pub account_number: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub country: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub state_province: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub postal_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub city: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub street_address: Option<String>,
+}
+
+/// # BankRelationship
+/// a bank account linked to an account for wire transfers.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+pub struct BankRelationship {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    pub name: String,
+    pub status: BankRelationshipStatus,
+    pub bank_code: String,
+    pub bank_code_type: BankCodeType,
+    pub account_number: String,
+    #[serde(default)]
+    pub country: Option<String>,
+    #[serde(default)]
+    pub state_province: Option<String>,
+    #[serde(default)]
+    pub postal_code: Option<String>,
+    #[serde(default)]
+    pub city: Option<String>,
+    #[serde(default)]
+    pub street_address: Option<String>,
+    #[serde(with = "rfc3339")]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::schema::DateTime"))]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "rfc3339")]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::schema::DateTime"))]
+    pub updated_at: OffsetDateTime,
+    /// fields Alpaca sent that this type does not know yet.
+    #[cfg(feature = "extra_fields")]
+    #[cfg_attr(not(feature = "deny_unknown_fields"), serde(flatten))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(skip))]
+    #[cfg_attr(feature = "ts", ts(skip))]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
+}
+
+/// # TransferType
+/// `ach` transfers go through an [`AchRelationship`], `wire` transfers through a
+/// [`BankRelationship`].
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum TransferType {
+    Ach,
+    Wire,
+}
+
+/// # TransferDirection
+/// `INCOMING` deposits into the account, `OUTGOING` withdraws from it.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TransferDirection {
+    Incoming,
+    Outgoing,
+}
+
+/// # TransferTiming
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum TransferTiming {
+    #[default]
+    Immediate,
+}
+
+/// # FeePaymentMethod
+/// Who pays the transfer fee, the `user` out of the transfer or the firm on its `invoice`.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum FeePaymentMethod {
+    User,
+    Invoice,
+}
+
+/// # TransferStatus
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TransferStatus {
+    Queued,
+    ApprovalPending,
+    Pending,
+    SentToClearing,
+    Rejected,
+    Canceled,
+    Approved,
+    /// the money has moved, this is final unless the transfer is `RETURNED`.
+    Complete,
+    /// the bank sent the transfer back, e.g. for insufficient funds.
+    Returned,
+    /// a status this crate does not model yet, so new statuses do not fail a whole event.
+    #[serde(other)]
+    Unknown,
+}
+
+/// # CreateTransferRequest
+/// moves money in or out of an account (POST /v1/accounts/{account_id}/transfers).
+/// `ach` transfers need a `relationship_id`, `wire` transfers a `bank_id`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct CreateTransferRequest {
+    pub transfer_type: TransferType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub relationship_id: Option<Uuid>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub bank_id: Option<Uuid>,
+    #[serde(serialize_with = "serialize_qty", deserialize_with = "string_to_f64")]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::schema::NumberString"))]
+    pub amount: f64,
+    pub direction: TransferDirection,
+    #[serde(default)]
+    pub timing: TransferTiming,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub fee_payment_method: Option<FeePaymentMethod>,
+    /// free text sent along with wire transfers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub additional_information: Option<String>,
+}
+
+/// # Transfer
+/// a deposit or withdrawal, as returned when creating or listing transfers.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+pub struct Transfer {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    /// the ACH relationship of `ach` transfers.
+    #[serde(default)]
+    pub relationship_id: Option<Uuid>,
+    /// the bank of `wire` transfers.
+    #[serde(default)]
+    pub bank_id: Option<Uuid>,
+    #[serde(rename = "type")]
+    pub transfer_type: TransferType,
+    pub status: TransferStatus,
+    /// why the transfer was rejected or returned.
+    #[serde(default)]
+    pub reason: Option<String>,
+    /// the amount that moves, i.e. `requested_amount` less any fee the user pays.
+    #[serde(serialize_with = "serialize_qty", deserialize_with = "string_to_f64")]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::schema::NumberString"))]
+    pub amount: f64,
+    #[serde(
+        default,
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(type = "string | null"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub requested_amount: Option<f64>,
+    #[serde(
+        default,
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(type = "string | null"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub fee: Option<f64>,
+    #[serde(default)]
+    pub fee_payment_method: Option<FeePaymentMethod>,
+    pub direction: TransferDirection,
+    #[serde(default)]
+    pub additional_information: Option<String>,
+    #[serde(with = "rfc3339")]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::schema::DateTime"))]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "rfc3339")]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::schema::DateTime"))]
+    pub updated_at: OffsetDateTime,
+    #[serde(default, with = "rfc3339::option")]
+    #[cfg_attr(feature = "ts", ts(type = "string | null"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::DateTime>")
+    )]
+    pub expires_at: Option<OffsetDateTime>,
+    /// deposited money cannot be withdrawn before this.
+    #[serde(default, with = "rfc3339::option")]
+    #[cfg_attr(feature = "ts", ts(type = "string | null"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::DateTime>")
+    )]
+    pub hold_until: Option<OffsetDateTime>,
+    /// fields Alpaca sent that this type does not know yet.
+    #[cfg(feature = "extra_fields")]
+    #[cfg_attr(not(feature = "deny_unknown_fields"), serde(flatten))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(skip))]
+    #[cfg_attr(feature = "ts", ts(skip))]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
+}
+
+/// # TransferStatusEvent
+/// an event of the transfer status stream (GET /v1/events/transfers/status),
+/// sent whenever a transfer's status changes.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+pub struct TransferStatusEvent {
+    pub account_id: Uuid,
+    pub transfer_id: Uuid,
+    #[serde(with = "rfc3339")]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::schema::DateTime"))]
+    pub at: OffsetDateTime,
+    /// the id to resume the stream from with `since_id`.
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(type = "number | null"))]
+    pub event_id: Option<u64>,
+    /// the id to resume the stream from with `since_ulid`.
+    #[serde(default)]
+    pub event_ulid: Option<String>,
+    /// `None` for the event of a new transfer.
+    #[serde(default, deserialize_with = "empty_string_is_none")]
+    pub status_from: Option<TransferStatus>,
+    #[serde(default, deserialize_with = "empty_string_is_none")]
+    pub status_to: Option<TransferStatus>,
+    /// fields Alpaca sent that this type does not know yet.
+    #[cfg(feature = "extra_fields")]
+    #[cfg_attr(not(feature = "deny_unknown_fields"), serde(flatten))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(skip))]
+    #[cfg_attr(feature = "ts", ts(skip))]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use super::{
+        AchRelationship, AchRelationshipStatus, BankAccountType, CreateTransferRequest,
+        FeePaymentMethod, Transfer, TransferDirection, TransferStatus, TransferStatusEvent,
+        TransferTiming, TransferType,
+    };
+
+    #[test]
+    fn create_transfer_request_serializes() {
+        let request = CreateTransferRequest {
+            transfer_type: TransferType::Ach,
+            relationship_id: Some(
+                Uuid::parse_str("794c3c51-71a8-4186-b5d0-247b6fb4045e").expect("bad uuid"),
+            ),
+            bank_id: None,
+            amount: 1234.56,
+            direction: TransferDirection::Incoming,
+            timing: TransferTiming::Immediate,
+            fee_payment_method: None,
+            additional_information: None,
+        };
+
+        assert_eq!(
+            serde_json::to_value(&request).expect("failed to serialize request"),
+            json!({
+                "transfer_type": "ach",
+                "relationship_id": "794c3c51-71a8-4186-b5d0-247b6fb4045e",
+                "amount": "1234.56",
+                "direction": "INCOMING",
+                "timing": "immediate"
+            })
+        );
+    }
+
+    #[test]
+    fn ach_relationship_parses() {
+        let payload = r#"
+        {
+            "id": "794c3c51-71a8-4186-b5d0-247b6fb4045e",
+            "account_id": "0d969814-40d6-4b2b-99ac-2e37427f692f",
+            "created_at": "2024-07-24T13:35:21.193385Z",
+            "updated_at": "2024-07-24T13:35:21.193385Z",
+            "status": "QUEUED",
+            "account_owner_name": "John Doe",
+            "bank_account_type": "CHECKING",
+            "bank_account_number": "32131231abc",
+            "bank_routing_number": "121000358",
+            "nickname": "Bank of America Checking"
+        }
+        "#;
+
+        let relationship = serde_json::from_str::<AchRelationship>(payload)
+            .expect("failed to parse ach relationship");
+        assert_eq!(relationship.status, AchRelationshipStatus::Queued);
+        assert_eq!(relationship.bank_account_type, BankAccountType::Checking);
+    }
+
+    #[test]
+    fn transfer_parses() {
+        let payload = r#"
+        {
+            "id": "be3c368a-4c7c-4384-808e-f02c9f5a8afe",
+            "relationship_id": "794c3c51-71a8-4186-b5d0-247b6fb4045e",
+            "bank_id": null,
+            "account_id": "0d969814-40d6-4b2b-99ac-2e37427f692f",
+            "type": "ach",
+            "status": "QUEUED",
+            "reason": null,
+            "amount": "1229.56",
+            "direction": "INCOMING",
+            "created_at": "2024-07-24T13:40:02.106941Z",
+            "updated_at": "2024-07-24T13:40:02.106941Z",
+            "expires_at": "2024-07-31T13:40:02.106941Z",
+            "requested_amount": "1234.56",
+            "fee": "5",
+            "fee_payment_method": "user",
+            "additional_information": null,
+            "hold_until": null
+        }
+        "#;
+
+        let transfer = serde_json::from_str::<Transfer>(payload).expect("failed to parse transfer");
+        assert_eq!(transfer.transfer_type, TransferType::Ach);
+        assert_eq!(transfer.status, TransferStatus::Queued);
+        assert_eq!(transfer.amount, 1229.56);
+        assert_eq!(transfer.fee, Some(5.0));
+        assert_eq!(transfer.fee_payment_method, Some(FeePaymentMethod::User));
+        assert_eq!(transfer.bank_id, None);
+    }
+
+    #[test]
+    fn transfer_status_event_parses() {
+        let payload = r#"
+        {
+            "account_id": "0d969814-40d6-4b2b-99ac-2e37427f692f",
+            "transfer_id": "be3c368a-4c7c-4384-808e-f02c9f5a8afe",
+            "at": "2024-07-24T13:40:02.115473Z",
+            "event_id": 2217,
+            "event_ulid": "01J3JNG5B3W7Q4C2KX0E5T4S8R",
+            "status_from": "",
+            "status_to": "QUEUED"
+        }
+        "#;
+
+        let event = serde_json::from_str::<TransferStatusEvent>(payload)
+            .expect("failed to parse transfer status event");
+        assert_eq!(event.status_from, None);
+        assert_eq!(event.status_to, Some(TransferStatus::Queued));
+
+        let payload = payload.replace("\"QUEUED\"", "\"HELD_FOR_REVIEW\"");
+        let event = serde_json::from_str::<TransferStatusEvent>(&payload)
+            .expect("failed to parse unknown transfer status");
+        assert_eq!(event.status_to, Some(TransferStatus::Unknown));
+    }
+}
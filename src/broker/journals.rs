@@ -0,0 +1,672 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use time::serde::rfc3339;
+use time::{Date, OffsetDateTime};
+use uuid::Uuid;
+
+use crate::{
+    date_from_opt_string, f64_from_opt_string, serialize_date, serialize_opt_qty, serialize_qty,
+    string_to_f64,
+};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "ts")]
+use ts_rs::TS;
+
+/// # JournalEntryType
+/// `JNLC` journals move cash, `JNLS` journals move shares.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum JournalEntryType {
+    #[serde(rename = "JNLC")]
+    Cash,
+    #[serde(rename = "JNLS")]
+    Securities,
+}
+
+impl Display for JournalEntryType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JournalEntryType::Cash => write!(f, "JNLC"),
+            JournalEntryType::Securities => write!(f, "JNLS"),
+        }
+    }
+}
+
+/// # JournalStatus
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum JournalStatus {
+    Queued,
+    SentToClearing,
+    Pending,
+    Executed,
+    Rejected,
+    Canceled,
+    Refused,
+    Correct,
+    Deleted,
+}
+
+/// # JournalValidationError
+/// describes why a journal request would be rejected.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JournalValidationError {
+    /// a field the entry type needs, `amount` for `JNLC` or `symbol` and `qty` for `JNLS`.
+    /// A blank `symbol` counts as missing.
+    MissingField {
+        entry_type: JournalEntryType,
+        field: &'static str,
+    },
+    /// a field of the other entry type, e.g. `qty` on a `JNLC`.
+    UnexpectedField {
+        entry_type: JournalEntryType,
+        field: &'static str,
+    },
+    /// the `amount` or `qty` was zero, negative or not a number.
+    NotPositive { field: &'static str, value: f64 },
+    /// a batch without entries.
+    EmptyBatch,
+    /// a reverse batch of a type other than `JNLC`, only cash can be collected in a batch.
+    NotReversible(JournalEntryType),
+    /// an entry of a batch is invalid.
+    Entry {
+        index: usize,
+        error: Box<JournalValidationError>,
+    },
+}
+
+impl Display for JournalValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JournalValidationError::MissingField { entry_type, field } => {
+                write!(f, "{entry_type} journals need {field}")
+            }
+            JournalValidationError::UnexpectedField { entry_type, field } => {
+                write!(f, "{entry_type} journals cannot have {field}")
+            }
+            JournalValidationError::NotPositive { field, value } => {
+                write!(f, "{field} must be greater than zero, got {value}")
+            }
+            JournalValidationError::EmptyBatch => write!(f, "batch has no entries"),
+            JournalValidationError::NotReversible(entry_type) => {
+                write!(f, "{entry_type} journals cannot be reversed in a batch")
+            }
+            JournalValidationError::Entry { index, error } => write!(f, "entry {index}: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for JournalValidationError {}
+
+/// checks an entry carries the fields of its type and nothing of the other type.
+fn validate_entry(
+    entry_type: JournalEntryType,
+    amount: Option<f64>,
+    symbol: Option<&str>,
+    qty: Option<f64>,
+) -> Result<(), JournalValidationError> {
+    let missing = |field| JournalValidationError::MissingField { entry_type, field };
+    let unexpected = |field| JournalValidationError::UnexpectedField { entry_type, field };
+    match entry_type {
+        JournalEntryType::Cash => {
+            if amount.is_none() {
+                return Err(missing("amount"));
+            }
+            if symbol.is_some() {
+                return Err(unexpected("symbol"));
+            }
+            if qty.is_some() {
+                return Err(unexpected("qty"));
+            }
+        }
+        JournalEntryType::Securities => {
+            if symbol.is_none_or(|symbol| symbol.trim().is_empty()) {
+                return Err(missing("symbol"));
+            }
+            if qty.is_none() {
+                return Err(missing("qty"));
+            }
+            if amount.is_some() {
+                return Err(unexpected("amount"));
+            }
+        }
+    }
+
+    for (field, value) in [("amount", amount), ("qty", qty)] {
+        if let Some(value) = value {
+            if !(value.is_finite() && value > 0.0) {
+                return Err(JournalValidationError::NotPositive { field, value });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// # JournalRequest
+/// moves cash or shares between two accounts (POST /v1/journals).
+/// `JNLC` journals need an `amount`, `JNLS` journals a `symbol` and `qty`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct JournalRequest {
+    pub from_account: Uuid,
+    pub to_account: Uuid,
+    pub entry_type: JournalEntryType,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub amount: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub symbol: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub qty: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub description: Option<String>,
+    /// the originator of the money, needed by the travel rule for cash journals over $3,000.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub transmitter_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub transmitter_account_number: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub transmitter_address: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub transmitter_financial_institution: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "rfc3339::option"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::DateTime>")
+    )]
+    pub transmitter_timestamp: Option<OffsetDateTime>,
+}
+
+impl JournalRequest {
+    /// moves `amount` dollars from one account to another.
+    pub fn cash(from_account: Uuid, to_account: Uuid, amount: f64) -> Self {
+        Self {
+            from_account,
+            to_account,
+            entry_type: JournalEntryType::Cash,
+            amount: Some(amount),
+            symbol: None,
+            qty: None,
+            description: None,
+            transmitter_name: None,
+            transmitter_account_number: None,
+            transmitter_address: None,
+            transmitter_financial_institution: None,
+            transmitter_timestamp: None,
+        }
+    }
+
+    /// moves `qty` shares of `symbol` from one account to another.
+    pub fn securities(
+        from_account: Uuid,
+        to_account: Uuid,
+        symbol: impl Into<String>,
+        qty: f64,
+    ) -> Self {
+        Self {
+            entry_type: JournalEntryType::Securities,
+            amount: None,
+            symbol: Some(symbol.into()),
+            qty: Some(qty),
+            ..Self::cash(from_account, to_account, 0.0)
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), JournalValidationError> {
+        validate_entry(
+            self.entry_type,
+            self.amount,
+            self.symbol.as_deref(),
+            self.qty,
+        )
+    }
+}
+
+/// # BatchJournalEntry
+/// one receiving account of a [`BatchJournalRequest`].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct BatchJournalEntry {
+    pub to_account: Uuid,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub amount: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub symbol: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub qty: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub description: Option<String>,
+}
+
+/// # BatchJournalRequest
+/// moves cash or shares from one account, usually the firm's, to many (POST /v1/journals/batch).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct BatchJournalRequest {
+    pub entry_type: JournalEntryType,
+    pub from_account: Uuid,
+    pub entries: Vec<BatchJournalEntry>,
+}
+
+impl BatchJournalRequest {
+    pub fn validate(&self) -> Result<(), JournalValidationError> {
+        if self.entries.is_empty() {
+            return Err(JournalValidationError::EmptyBatch);
+        }
+        for (index, entry) in self.entries.iter().enumerate() {
+            validate_entry(
+                self.entry_type,
+                entry.amount,
+                entry.symbol.as_deref(),
+                entry.qty,
+            )
+            .map_err(|error| JournalValidationError::Entry {
+                index,
+                error: Box::new(error),
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// # ReverseBatchJournalEntry
+/// one sending account of a [`ReverseBatchJournalRequest`].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct ReverseBatchJournalEntry {
+    pub from_account: Uuid,
+    #[serde(serialize_with = "serialize_qty", deserialize_with = "string_to_f64")]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::schema::NumberString"))]
+    pub amount: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub description: Option<String>,
+}
+
+/// # ReverseBatchJournalRequest
+/// moves cash from many accounts into one, e.g. to collect fees (POST /v1/journals/reverse_batch).
+/// Only `JNLC` journals can be reversed in a batch.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct ReverseBatchJournalRequest {
+    pub entry_type: JournalEntryType,
+    pub to_account: Uuid,
+    pub entries: Vec<ReverseBatchJournalEntry>,
+}
+
+impl ReverseBatchJournalRequest {
+    pub fn validate(&self) -> Result<(), JournalValidationError> {
+        if self.entry_type != JournalEntryType::Cash {
+            return Err(JournalValidationError::NotReversible(self.entry_type));
+        }
+        if self.entries.is_empty() {
+            return Err(JournalValidationError::EmptyBatch);
+        }
+        for (index, entry) in self.entries.iter().enumerate() {
+            validate_entry(self.entry_type, Some(entry.amount), None, None).map_err(|error| {
+                JournalValidationError::Entry {
+                    index,
+                    error: Box::new(error),
+                }
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// # Journal
+/// a journal, as returned when creating, getting or listing journals.
+/// Batch requests return one per entry.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+pub struct Journal {
+    pub id: Uuid,
+    pub entry_type: JournalEntryType,
+    pub from_account: Uuid,
+    pub to_account: Uuid,
+    pub status: JournalStatus,
+    #[serde(default)]
+    pub symbol: Option<String>,
+    #[serde(
+        default,
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(type = "string | null"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub qty: Option<f64>,
+    /// the price the shares of a `JNLS` journal were valued at.
+    #[serde(
+        default,
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(type = "string | null"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub price: Option<f64>,
+    /// the cash moved, `0` for `JNLS` journals.
+    #[serde(serialize_with = "serialize_qty", deserialize_with = "string_to_f64")]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::schema::NumberString"))]
+    pub net_amount: f64,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(
+        default,
+        serialize_with = "serialize_date",
+        deserialize_with = "date_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(type = "string | null"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::FullDate>")
+    )]
+    pub settle_date: Option<Date>,
+    #[serde(
+        default,
+        serialize_with = "serialize_date",
+        deserialize_with = "date_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(type = "string | null"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::FullDate>")
+    )]
+    pub system_date: Option<Date>,
+    #[serde(default)]
+    pub currency: Option<String>,
+    #[serde(default)]
+    pub transmitter_name: Option<String>,
+    #[serde(default)]
+    pub transmitter_account_number: Option<String>,
+    #[serde(default)]
+    pub transmitter_address: Option<String>,
+    #[serde(default)]
+    pub transmitter_financial_institution: Option<String>,
+    #[serde(default, with = "rfc3339::option")]
+    #[cfg_attr(feature = "ts", ts(type = "string | null"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::DateTime>")
+    )]
+    pub transmitter_timestamp: Option<OffsetDateTime>,
+    /// why an entry of a batch was not journaled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub error_message: Option<String>,
+    /// fields Alpaca sent that this type does not know yet.
+    #[cfg(feature = "extra_fields")]
+    #[cfg_attr(not(feature = "deny_unknown_fields"), serde(flatten))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(skip))]
+    #[cfg_attr(feature = "ts", ts(skip))]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use time::macros::date;
+    use uuid::Uuid;
+
+    use super::{
+        BatchJournalEntry, BatchJournalRequest, Journal, JournalEntryType, JournalRequest,
+        JournalStatus, JournalValidationError, ReverseBatchJournalEntry,
+        ReverseBatchJournalRequest,
+    };
+
+    const FIRM_ACCOUNT: &str = "8f8c8cee-2591-4f83-be12-82c659b5e748";
+    const USER_ACCOUNT: &str = "0d969814-40d6-4b2b-99ac-2e37427f692f";
+
+    fn accounts() -> (Uuid, Uuid) {
+        (
+            Uuid::parse_str(FIRM_ACCOUNT).expect("bad uuid"),
+            Uuid::parse_str(USER_ACCOUNT).expect("bad uuid"),
+        )
+    }
+
+    #[test]
+    fn journal_requests_serialize() {
+        let (firm, user) = accounts();
+        let request = JournalRequest {
+            description: Some("sign up reward".to_string()),
+            ..JournalRequest::cash(firm, user, 25.5)
+        };
+        assert_eq!(request.validate(), Ok(()));
+        assert_eq!(
+            serde_json::to_value(&request).expect("failed to serialize request"),
+            json!({
+                "from_account": FIRM_ACCOUNT,
+                "to_account": USER_ACCOUNT,
+                "entry_type": "JNLC",
+                "amount": "25.5",
+                "description": "sign up reward"
+            })
+        );
+
+        let request = JournalRequest::securities(firm, user, "AAPL", 0.5);
+        assert_eq!(request.validate(), Ok(()));
+        assert_eq!(
+            serde_json::to_value(&request).expect("failed to serialize request"),
+            json!({
+                "from_account": FIRM_ACCOUNT,
+                "to_account": USER_ACCOUNT,
+                "entry_type": "JNLS",
+                "symbol": "AAPL",
+                "qty": "0.5"
+            })
+        );
+    }
+
+    #[test]
+    fn journal_requests_validate() {
+        let (firm, user) = accounts();
+
+        let request = JournalRequest {
+            qty: None,
+            ..JournalRequest::securities(firm, user, "AAPL", 1.0)
+        };
+        assert_eq!(
+            request.validate(),
+            Err(JournalValidationError::MissingField {
+                entry_type: JournalEntryType::Securities,
+                field: "qty"
+            })
+        );
+
+        let request = JournalRequest {
+            amount: None,
+            ..JournalRequest::cash(firm, user, 10.0)
+        };
+        assert_eq!(
+            request.validate(),
+            Err(JournalValidationError::MissingField {
+                entry_type: JournalEntryType::Cash,
+                field: "amount"
+            })
+        );
+
+        let request = JournalRequest {
+            symbol: Some("AAPL".to_string()),
+            ..JournalRequest::cash(firm, user, 10.0)
+        };
+        assert_eq!(
+            request.validate(),
+            Err(JournalValidationError::UnexpectedField {
+                entry_type: JournalEntryType::Cash,
+                field: "symbol"
+            })
+        );
+
+        let request = JournalRequest::securities(firm, user, " ", 1.0);
+        assert_eq!(
+            request.validate(),
+            Err(JournalValidationError::MissingField {
+                entry_type: JournalEntryType::Securities,
+                field: "symbol"
+            })
+        );
+
+        let request = JournalRequest::cash(firm, user, -10.0);
+        assert_eq!(
+            request.validate(),
+            Err(JournalValidationError::NotPositive {
+                field: "amount",
+                value: -10.0
+            })
+        );
+    }
+
+    #[test]
+    fn batch_requests_validate() {
+        let (firm, user) = accounts();
+        let entry = BatchJournalEntry {
+            to_account: user,
+            amount: Some(10.0),
+            symbol: None,
+            qty: None,
+            description: None,
+        };
+        let mut batch = BatchJournalRequest {
+            entry_type: JournalEntryType::Cash,
+            from_account: firm,
+            entries: vec![entry.clone()],
+        };
+        assert_eq!(batch.validate(), Ok(()));
+
+        batch.entries.push(BatchJournalEntry {
+            amount: None,
+            ..entry
+        });
+        let error = batch.validate().expect_err("entry without amount");
+        assert_eq!(error.to_string(), "entry 1: JNLC journals need amount");
+
+        batch.entries.clear();
+        assert_eq!(batch.validate(), Err(JournalValidationError::EmptyBatch));
+
+        let reverse = ReverseBatchJournalRequest {
+            entry_type: JournalEntryType::Securities,
+            to_account: firm,
+            entries: vec![ReverseBatchJournalEntry {
+                from_account: user,
+                amount: 5.0,
+                description: None,
+            }],
+        };
+        assert_eq!(
+            reverse.validate(),
+            Err(JournalValidationError::NotReversible(
+                JournalEntryType::Securities
+            ))
+        );
+        let reverse = ReverseBatchJournalRequest {
+            entry_type: JournalEntryType::Cash,
+            ..reverse
+        };
+        assert_eq!(reverse.validate(), Ok(()));
+    }
+
+    #[test]
+    fn journal_parses() {
+        let payload = r#"
+        {
+            "id": "d5f7a9e2-52f0-4b5e-8c2b-1b8f2c8e6a41",
+            "entry_type": "JNLS",
+            "from_account": "8f8c8cee-2591-4f83-be12-82c659b5e748",
+            "to_account": "0d969814-40d6-4b2b-99ac-2e37427f692f",
+            "symbol": "AAPL",
+            "qty": "0.5",
+            "price": "218.54",
+            "status": "executed",
+            "settle_date": "2024-07-26",
+            "system_date": "2024-07-24",
+            "net_amount": "0",
+            "description": "sign up reward",
+            "currency": "USD",
+            "transmitter_name": null,
+            "transmitter_account_number": null,
+            "transmitter_address": null,
+            "transmitter_financial_institution": null,
+            "transmitter_timestamp": null
+        }
+        "#;
+
+        let journal = serde_json::from_str::<Journal>(payload).expect("failed to parse journal");
+        assert_eq!(journal.entry_type, JournalEntryType::Securities);
+        assert_eq!(journal.status, JournalStatus::Executed);
+        assert_eq!(journal.qty, Some(0.5));
+        assert_eq!(journal.settle_date, Some(date!(2024 - 07 - 26)));
+        assert_eq!(journal.error_message, None);
+    }
+}
@@ -0,0 +1,6 @@
+//! types for the Broker API (https://broker-api.alpaca.markets), used to open, fund and
+//! manage accounts on behalf of end users.
+
+pub mod accounts;
+pub mod funding;
+pub mod journals;
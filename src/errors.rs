@@ -1,13 +1,377 @@
-use http_serde::http::StatusCode;
-use serde::Deserialize;
+use std::fmt::Display;
 
-#[derive(Deserialize, Debug)]
+use serde::{Deserialize, Serialize};
+
+use crate::{f64_from_opt_string, serialize_opt_qty};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "ts")]
+use ts_rs::TS;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 /// # ErrorMessage
 /// defines the general error message received when an error has occured calling the API.
-/// * `code`: the error code belonging to this error.
+/// * `code`: the error code belonging to this error, either an http status code or
+///   Alpaca's own 8 digit error code (e.g. `40310000`).
 /// * `message`: describes what went wrong.
 pub struct ErrorMessage {
-    #[serde(with = "http_serde::status_code")]
-    pub code: StatusCode,
+    pub code: u32,
     pub message: String,
 }
+
+impl ErrorMessage {
+    /// the http status code embedded in the first three digits of `code`.
+    pub fn status(&self) -> Option<u16> {
+        status_from_error_code(self.code)
+    }
+}
+
+/// the http status code in the first three digits of an Alpaca error code,
+/// or the code itself when it already is a status.
+fn status_from_error_code(mut code: u32) -> Option<u16> {
+    while code > 999 {
+        code /= 10;
+    }
+    u16::try_from(code).ok().filter(|status| *status >= 100)
+}
+
+/// # ApiError
+/// the wire layout of an error body returned by the trading API.
+/// Only `code` and `message` are always present, the rest depend on the error.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+pub struct ApiError {
+    /// Alpaca's 8 digit error code, e.g. `40310000`. Zero when the body had no code.
+    #[serde(default)]
+    pub code: u32,
+    #[serde(default)]
+    pub message: String,
+    /// the orders that conflict with the rejected one, e.g. for potential wash trades.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "ts", ts(as = "Option<Vec<String>>", optional))]
+    pub related_orders: Vec<String>,
+    /// the quantity available to trade when a qty is rejected.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub available: Option<f64>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub existing_qty: Option<f64>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub held_for_orders: Option<f64>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub buying_power: Option<f64>,
+    /// the cost of the rejected order when buying power is insufficient.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub cost: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub symbol: Option<String>,
+    /// why a potential wash trade was rejected.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub reject_reason: Option<String>,
+    /// fields Alpaca sent that this type does not know yet.
+    #[cfg(feature = "extra_fields")]
+    #[cfg_attr(not(feature = "deny_unknown_fields"), serde(flatten))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(skip))]
+    #[cfg_attr(feature = "ts", ts(skip))]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
+}
+
+impl ApiError {
+    /// the http status code embedded in the first three digits of `code`.
+    pub fn status(&self) -> Option<u16> {
+        status_from_error_code(self.code)
+    }
+}
+
+/// the error code Alpaca uses for orders rejected by pattern day trader protection.
+const PATTERN_DAY_TRADER_CODE: u32 = 40310100;
+
+/// # AlpacaError
+/// An [`ApiError`] classified by its code and message. Many errors share a code
+/// (e.g. every forbidden trade is `40310000`), so the message is used to tell them apart.
+/// Errors that cannot be classified fall back to `Unknown` instead of failing to parse.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS), ts(as = "ApiError"))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(from = "ApiError", into = "ApiError")]
+pub enum AlpacaError {
+    BadRequest(ApiError),
+    Unauthorized(ApiError),
+    Forbidden(ApiError),
+    InsufficientBuyingPower(ApiError),
+    InsufficientQty(ApiError),
+    PatternDayTrader(ApiError),
+    WashTrade(ApiError),
+    NotFound(ApiError),
+    SymbolNotFound(ApiError),
+    Unprocessable(ApiError),
+    RateLimited(ApiError),
+    ServerError(ApiError),
+    Unknown(ApiError),
+}
+
+impl AlpacaError {
+    /// classifies an error body, using the response's http `status` when the body
+    /// has no code (e.g. rate limited responses only carry a message).
+    pub fn from_status(status: u16, mut body: ApiError) -> AlpacaError {
+        if body.code == 0 {
+            body.code = u32::from(status) * 100_000;
+        }
+        AlpacaError::from(body)
+    }
+
+    /// parses a raw response body, falling back to the http `status` when the body
+    /// is not a json error, e.g. an html page from a proxy.
+    pub fn from_response(status: u16, body: &[u8]) -> AlpacaError {
+        let api_error = serde_json::from_slice::<ApiError>(body).unwrap_or_else(|_| ApiError {
+            message: String::from_utf8_lossy(body).into_owned(),
+            ..Default::default()
+        });
+        AlpacaError::from_status(status, api_error)
+    }
+
+    pub fn api_error(&self) -> &ApiError {
+        match self {
+            AlpacaError::BadRequest(error)
+            | AlpacaError::Unauthorized(error)
+            | AlpacaError::Forbidden(error)
+            | AlpacaError::InsufficientBuyingPower(error)
+            | AlpacaError::InsufficientQty(error)
+            | AlpacaError::PatternDayTrader(error)
+            | AlpacaError::WashTrade(error)
+            | AlpacaError::NotFound(error)
+            | AlpacaError::SymbolNotFound(error)
+            | AlpacaError::Unprocessable(error)
+            | AlpacaError::RateLimited(error)
+            | AlpacaError::ServerError(error)
+            | AlpacaError::Unknown(error) => error,
+        }
+    }
+
+    pub fn code(&self) -> u32 {
+        self.api_error().code
+    }
+
+    pub fn message(&self) -> &str {
+        &self.api_error().message
+    }
+
+    pub fn related_orders(&self) -> &[String] {
+        &self.api_error().related_orders
+    }
+
+    pub fn available(&self) -> Option<f64> {
+        self.api_error().available
+    }
+
+    /// true when sending the same request again later may succeed.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            AlpacaError::RateLimited(_) | AlpacaError::ServerError(_)
+        )
+    }
+}
+
+impl From<ApiError> for AlpacaError {
+    fn from(error: ApiError) -> Self {
+        let message = error.message.to_lowercase();
+        let mentions_symbol = message.contains("symbol") || message.contains("asset");
+
+        match error.status() {
+            _ if error.code == PATTERN_DAY_TRADER_CODE => AlpacaError::PatternDayTrader(error),
+            Some(400) => AlpacaError::BadRequest(error),
+            Some(401) => AlpacaError::Unauthorized(error),
+            Some(403) if message.contains("buying power") => {
+                AlpacaError::InsufficientBuyingPower(error)
+            }
+            Some(403) if message.contains("insufficient qty") => {
+                AlpacaError::InsufficientQty(error)
+            }
+            Some(403) if message.contains("pattern day") => AlpacaError::PatternDayTrader(error),
+            Some(403) if message.contains("wash trade") || !error.related_orders.is_empty() => {
+                AlpacaError::WashTrade(error)
+            }
+            Some(403) => AlpacaError::Forbidden(error),
+            Some(404) if mentions_symbol => AlpacaError::SymbolNotFound(error),
+            Some(404) => AlpacaError::NotFound(error),
+            Some(422)
+                if mentions_symbol
+                    && (message.contains("not found") || message.contains("invalid")) =>
+            {
+                AlpacaError::SymbolNotFound(error)
+            }
+            Some(422) => AlpacaError::Unprocessable(error),
+            Some(429) => AlpacaError::RateLimited(error),
+            Some(500..=599) => AlpacaError::ServerError(error),
+            _ => AlpacaError::Unknown(error),
+        }
+    }
+}
+
+impl From<AlpacaError> for ApiError {
+    fn from(error: AlpacaError) -> Self {
+        match error {
+            AlpacaError::BadRequest(error)
+            | AlpacaError::Unauthorized(error)
+            | AlpacaError::Forbidden(error)
+            | AlpacaError::InsufficientBuyingPower(error)
+            | AlpacaError::InsufficientQty(error)
+            | AlpacaError::PatternDayTrader(error)
+            | AlpacaError::WashTrade(error)
+            | AlpacaError::NotFound(error)
+            | AlpacaError::SymbolNotFound(error)
+            | AlpacaError::Unprocessable(error)
+            | AlpacaError::RateLimited(error)
+            | AlpacaError::ServerError(error)
+            | AlpacaError::Unknown(error) => error,
+        }
+    }
+}
+
+impl Display for AlpacaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "alpaca error {}: {}", self.code(), self.message())
+    }
+}
+
+impl std::error::Error for AlpacaError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{AlpacaError, ErrorMessage};
+
+    #[test]
+    fn error_message_parses_alpaca_codes() {
+        let input = r#"{"code": 40310000, "message": "insufficient buying power"}"#;
+        let parsed = serde_json::from_str::<ErrorMessage>(input).expect("failed to parse");
+        assert_eq!(parsed.code, 40310000);
+        assert_eq!(parsed.status(), Some(403));
+        assert_eq!(
+            serde_json::to_value(&parsed).expect("failed to serialize")["code"],
+            40310000
+        );
+    }
+
+    #[test]
+    fn classifies_forbidden_errors() {
+        let input = r#"
+        {
+            "buying_power": "0",
+            "code": 40310000,
+            "cost": "1",
+            "message": "insufficient buying power"
+        }
+        "#;
+        let parsed = serde_json::from_str::<AlpacaError>(input).expect("failed to parse");
+        assert!(matches!(parsed, AlpacaError::InsufficientBuyingPower(_)));
+        assert_eq!(parsed.api_error().cost, Some(1.0));
+        assert!(!parsed.is_retryable());
+
+        let input = r#"
+        {
+            "available": "0",
+            "code": 40310000,
+            "existing_qty": "100",
+            "held_for_orders": "100",
+            "message": "insufficient qty available for order (requested: 100, available: 0)",
+            "symbol": "PENN"
+        }
+        "#;
+        let parsed = serde_json::from_str::<AlpacaError>(input).expect("failed to parse");
+        assert!(matches!(parsed, AlpacaError::InsufficientQty(_)));
+        assert_eq!(parsed.available(), Some(0.0));
+
+        let input = r#"{"code": 40310100, "message": "trade denied due to pattern day trading protection"}"#;
+        let parsed = serde_json::from_str::<AlpacaError>(input).expect("failed to parse");
+        assert!(matches!(parsed, AlpacaError::PatternDayTrader(_)));
+
+        let input = r#"
+        {
+            "code": 40310000,
+            "message": "potential wash trade detected. use complex orders",
+            "reject_reason": "opposite side market/stop order exists",
+            "related_orders": ["6c1ddd83-e8e4-4b85-a2a3-7c30b8bca4dd"]
+        }
+        "#;
+        let parsed = serde_json::from_str::<AlpacaError>(input).expect("failed to parse");
+        assert!(matches!(parsed, AlpacaError::WashTrade(_)));
+        assert_eq!(parsed.related_orders().len(), 1);
+    }
+
+    #[test]
+    fn classifies_by_status_and_falls_back() {
+        let parsed = AlpacaError::from_response(429, br#"{"message": "too many requests."}"#);
+        assert!(matches!(parsed, AlpacaError::RateLimited(_)));
+        assert!(parsed.is_retryable());
+
+        let parsed = AlpacaError::from_response(502, b"<html>bad gateway</html>");
+        assert!(matches!(parsed, AlpacaError::ServerError(_)));
+        assert!(parsed.is_retryable());
+
+        let input = r#"{"code": 42210000, "message": "asset \"FOO\" not found"}"#;
+        let parsed = serde_json::from_str::<AlpacaError>(input).expect("failed to parse");
+        assert!(matches!(parsed, AlpacaError::SymbolNotFound(_)));
+
+        let input = r#"{"code": 12345678, "message": "something new"}"#;
+        let parsed = serde_json::from_str::<AlpacaError>(input).expect("unknown code failed");
+        assert!(matches!(parsed, AlpacaError::Unknown(_)));
+        assert_eq!(parsed.code(), 12345678);
+    }
+}
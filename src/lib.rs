@@ -1,9 +1,19 @@
+pub mod broker;
 pub mod errors;
 pub mod market_data;
 pub mod orders;
 pub mod positions;
+pub mod rate_limit;
+#[cfg(feature = "schemars")]
+pub mod schema;
 pub mod trading;
 
+#[cfg(all(test, feature = "ts"))]
+mod bindings;
+#[cfg(test)]
+mod round_trip;
+
+use serde::de::IntoDeserializer;
 use serde::{de, Deserialize, Deserializer, Serializer};
 
 /// custom deserialization for converting Option<String> to Option<f64> where needed.
@@ -68,3 +78,80 @@ where
     let qty_str = qty.to_string();
     serializer.serialize_str(&qty_str)
 }
+
+/// custom serializer to convert an optional float to a string.
+/// `None` is written as null, skip it with `skip_serializing_if = "Option::is_none"` for request
+/// fields that should be left out instead.
+/// * `value`: optional float amount to serialize
+/// * `serializer`: S
+fn serialize_opt_qty<S>(value: &Option<f64>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match value {
+        Some(num) => serializer.serialize_str(&num.to_string()),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// custom serializer to join a list into the comma separated value Alpaca expects
+/// in query strings, e.g. `symbols=AAPL,TSLA`.
+/// `None` values should be skipped with `skip_serializing_if = "Option::is_none"`.
+fn serialize_comma_separated<S>(
+    values: &Option<Vec<String>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match values {
+        Some(values) => serializer.serialize_str(&values.join(",")),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// custom deserializer to split a comma separated value into a list.
+fn comma_separated_from_opt_string<'de, D>(deserializer: D) -> Result<Option<Vec<String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let opt: Option<String> = Option::deserialize(deserializer)?;
+    Ok(opt.map(|values| values.split(',').map(str::to_string).collect()))
+}
+
+/// custom serializer to write an optional date as `YYYY-MM-DD`.
+fn serialize_date<S>(date: &Option<time::Date>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match date {
+        Some(date) => serializer.collect_str(date),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// custom deserializer to parse an optional `YYYY-MM-DD` date.
+fn date_from_opt_string<'de, D>(deserializer: D) -> Result<Option<time::Date>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let format = time::macros::format_description!("[year]-[month]-[day]");
+    let opt: Option<String> = Option::deserialize(deserializer)?;
+    opt.map(|date| time::Date::parse(&date, format).map_err(de::Error::custom))
+        .transpose()
+}
+
+/// custom deserializer for optional values Alpaca sends as an empty string instead of null,
+/// e.g. the previous status in the first event of a stream.
+fn empty_string_is_none<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    let opt: Option<String> = Option::deserialize(deserializer)?;
+    match opt {
+        None => Ok(None),
+        Some(value) if value.is_empty() => Ok(None),
+        Some(value) => T::deserialize(value.into_deserializer()).map(Some),
+    }
+}
@@ -1,19 +1,38 @@
 use std::collections::HashMap;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "ts")]
+use ts_rs::TS;
+
 /// The latest minute-aggregated historical bar data for each of the crypto symbols provided.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 pub struct LatestCryptoBars {
     pub bars: HashMap<String, CryptoBar>,
+    /// fields Alpaca sent that this type does not know yet.
+    #[cfg(feature = "extra_fields")]
+    #[cfg_attr(not(feature = "deny_unknown_fields"), serde(flatten))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(skip))]
+    #[cfg_attr(feature = "ts", ts(skip))]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 
 /// CryptoBar represents OHLC candlestick data (minute or daily)
 /// The differen between Bar types is the volume, as cryptocurrencies are usually traded in fractional amounts.
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 pub struct CryptoBar {
     #[serde(rename = "t", with = "time::serde::rfc3339")]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::schema::DateTime"))]
     pub timestamp: OffsetDateTime,
 
     #[serde(rename = "o")]
@@ -32,6 +51,7 @@ pub struct CryptoBar {
     pub volume: f64,
 
     #[serde(rename = "n")]
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
     pub trade_count: i64,
 
     #[serde(rename = "vw")]
@@ -1,2 +1,33 @@
+use time::OffsetDateTime;
+
 pub mod crypto;
 pub mod stock;
+
+/// # PriceBar
+/// a bar with a timestamp and a close, so stock and crypto bars can be used alike
+/// when lining them up with other series.
+pub trait PriceBar {
+    /// the start of the bar.
+    fn timestamp(&self) -> OffsetDateTime;
+    fn close(&self) -> f64;
+}
+
+impl PriceBar for crypto::CryptoBar {
+    fn timestamp(&self) -> OffsetDateTime {
+        self.timestamp
+    }
+
+    fn close(&self) -> f64 {
+        self.close
+    }
+}
+
+impl PriceBar for stock::Bar {
+    fn timestamp(&self) -> OffsetDateTime {
+        self.timestamp
+    }
+
+    fn close(&self) -> f64 {
+        self.close
+    }
+}
@@ -1,16 +1,22 @@
 use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
 
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
 #[cfg(feature = "ts")]
 use ts_rs::TS;
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
-#[cfg_attr(feature = "ts", derive(TS), ts(export))]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 pub struct Trade {
     #[serde(rename = "c")]
     pub condition_flags: Vec<String>,
     #[serde(rename = "i")]
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
     pub trade_id: i64,
     #[serde(rename = "p")]
     pub price: f64,
@@ -24,15 +30,71 @@ pub struct Trade {
     pub tape: String,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
-#[cfg_attr(feature = "ts", derive(TS), ts(export))]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 pub struct LatestTrades {
     pub trades: HashMap<String, Trade>,
+    /// fields Alpaca sent that this type does not know yet.
+    #[cfg(feature = "extra_fields")]
+    #[cfg_attr(not(feature = "deny_unknown_fields"), serde(flatten))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(skip))]
+    #[cfg_attr(feature = "ts", ts(skip))]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
+}
+
+/// # Bar
+/// OHLC candlestick data of a stock over the bar's timeframe, e.g. a minute or a day.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+pub struct Bar {
+    /// the start of the bar.
+    #[serde(rename = "t", with = "time::serde::rfc3339")]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::schema::DateTime"))]
+    pub timestamp: OffsetDateTime,
+    #[serde(rename = "o")]
+    pub open: f64,
+    #[serde(rename = "h")]
+    pub high: f64,
+    #[serde(rename = "l")]
+    pub low: f64,
+    #[serde(rename = "c")]
+    pub close: f64,
+    #[serde(rename = "v")]
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
+    pub volume: u64,
+    #[serde(rename = "n")]
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
+    pub trade_count: u64,
+    #[serde(rename = "vw")]
+    pub vwap: f64,
+}
+
+/// # Bars
+/// historical bars for each of the requested symbols (GET /v2/stocks/bars).
+/// `next_page_token` is set when there are more bars to fetch.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+pub struct Bars {
+    pub bars: HashMap<String, Vec<Bar>>,
+    pub next_page_token: Option<String>,
+    /// fields Alpaca sent that this type does not know yet.
+    #[cfg(feature = "extra_fields")]
+    #[cfg_attr(not(feature = "deny_unknown_fields"), serde(flatten))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(skip))]
+    #[cfg_attr(feature = "ts", ts(skip))]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
 }
 
 #[cfg(test)]
 mod test {
-    use crate::market_data::stock::LatestTrades;
+    use crate::market_data::stock::{Bars, LatestTrades};
 
     #[test]
     fn latest_trade_parses() {
@@ -59,4 +121,31 @@ mod test {
         let got = serde_json::from_str::<LatestTrades>(latest_trade);
         assert!(got.is_ok());
     }
+
+    #[test]
+    fn bars_parse() {
+        let bars = r#"
+        {
+            "bars": {
+                "SPY": [
+                    {
+                        "t": "2024-07-24T04:00:00Z",
+                        "o": 548.86,
+                        "h": 549.17,
+                        "l": 540.29,
+                        "c": 541.23,
+                        "v": 74515355,
+                        "n": 936584,
+                        "vw": 543.262
+                    }
+                ]
+            },
+            "next_page_token": null
+        }
+        "#;
+
+        let got = serde_json::from_str::<Bars>(bars).expect("failed to parse bars");
+        assert_eq!(got.bars["SPY"][0].close, 541.23);
+        assert_eq!(got.next_page_token, None);
+    }
 }
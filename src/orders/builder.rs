@@ -0,0 +1,820 @@
+use std::fmt::Display;
+
+use super::{
+    OrderClass, OrderRequest, OrderSide, OrderType, PositionIntent, StopLoss, TakeProfit,
+    TimeInForce,
+};
+
+/// The maximum length Alpaca accepts for a `client_order_id`.
+const MAX_CLIENT_ORDER_ID_LEN: usize = 128;
+
+/// # OrderValidationError
+/// describes why an order would be rejected by Alpaca before it is ever sent.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OrderValidationError {
+    /// `symbol` or `side` was not given, which only `mleg` orders can leave out.
+    MissingField(&'static str),
+    /// neither `qty` nor `notional` was given.
+    MissingQuantity,
+    /// both `qty` and `notional` were given.
+    QtyAndNotional,
+    /// a quantity or price field was zero, negative or not a number.
+    NotPositive { field: &'static str, value: f64 },
+    /// the order type requires a price field that was not given.
+    MissingPrice {
+        order_type: OrderType,
+        field: &'static str,
+    },
+    /// a price field was given that the order type does not use.
+    UnexpectedPrice {
+        order_type: OrderType,
+        field: &'static str,
+    },
+    /// trailing stops need exactly one of `trail_price` or `trail_percent`.
+    InvalidTrail,
+    /// the time in force cannot be used with this order type or class.
+    InvalidTimeInForce {
+        order_type: OrderType,
+        time_in_force: TimeInForce,
+    },
+    /// extended hours are only available for `limit` orders with a `day` time in force.
+    ExtendedHoursRequiresLimitDay,
+    /// fractional and notional orders must be `market` orders with a `day` time in force.
+    FractionalRequiresMarketDay,
+    /// a bracket, oco, oto or mleg order is missing a leg or has inconsistent legs.
+    InvalidAdvancedOrder(&'static str),
+    /// the client order id is longer than Alpaca allows.
+    ClientOrderIdTooLong(usize),
+}
+
+impl Display for OrderValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OrderValidationError::MissingField(field) => write!(f, "{field} is required"),
+            OrderValidationError::MissingQuantity => {
+                write!(f, "one of qty or notional is required")
+            }
+            OrderValidationError::QtyAndNotional => {
+                write!(f, "qty and notional cannot be used together")
+            }
+            OrderValidationError::NotPositive { field, value } => {
+                write!(f, "{field} must be greater than zero, got {value}")
+            }
+            OrderValidationError::MissingPrice { order_type, field } => {
+                write!(f, "{field} is required for {order_type} orders")
+            }
+            OrderValidationError::UnexpectedPrice { order_type, field } => {
+                write!(f, "{field} is not used by {order_type} orders")
+            }
+            OrderValidationError::InvalidTrail => write!(
+                f,
+                "trailing_stop orders need exactly one of trail_price or trail_percent"
+            ),
+            OrderValidationError::InvalidTimeInForce {
+                order_type,
+                time_in_force,
+            } => write!(
+                f,
+                "time in force {time_in_force} is not allowed for {order_type} orders"
+            ),
+            OrderValidationError::ExtendedHoursRequiresLimitDay => write!(
+                f,
+                "extended hours orders must be limit orders with a day time in force"
+            ),
+            OrderValidationError::FractionalRequiresMarketDay => write!(
+                f,
+                "fractional and notional orders must be market orders with a day time in force"
+            ),
+            OrderValidationError::InvalidAdvancedOrder(why) => write!(f, "{why}"),
+            OrderValidationError::ClientOrderIdTooLong(len) => write!(
+                f,
+                "client_order_id is {len} characters, the maximum is {MAX_CLIENT_ORDER_ID_LEN}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for OrderValidationError {}
+
+/// errors if `value` is not a finite number greater than zero.
+fn ensure_positive(field: &'static str, value: f64) -> Result<(), OrderValidationError> {
+    if value.is_finite() && value > 0.0 {
+        Ok(())
+    } else {
+        Err(OrderValidationError::NotPositive { field, value })
+    }
+}
+
+/// errors if the price `field` is set or not set when the order type says otherwise.
+fn ensure_price(
+    order_type: &OrderType,
+    field: &'static str,
+    value: Option<f64>,
+    required: bool,
+) -> Result<(), OrderValidationError> {
+    match (value, required) {
+        (Some(price), true) => ensure_positive(field, price),
+        (None, false) => Ok(()),
+        (None, true) => Err(OrderValidationError::MissingPrice {
+            order_type: order_type.clone(),
+            field,
+        }),
+        (Some(_), false) => Err(OrderValidationError::UnexpectedPrice {
+            order_type: order_type.clone(),
+            field,
+        }),
+    }
+}
+
+impl OrderRequest {
+    /// checks the request against the same rules Alpaca applies when an order is submitted.
+    /// Orders built with [`OrderRequestBuilder`] are validated automatically.
+    pub fn validate(&self) -> Result<(), OrderValidationError> {
+        let order_type = &self.order_type;
+        let time_in_force = &self.time_in_force;
+
+        if self.order_class != Some(OrderClass::Mleg) {
+            if self.symbol.as_deref().is_none_or(str::is_empty) {
+                return Err(OrderValidationError::MissingField("symbol"));
+            }
+            if self.side.is_none() {
+                return Err(OrderValidationError::MissingField("side"));
+            }
+        }
+
+        let is_fractional = match (self.qty, self.notional) {
+            (Some(_), Some(_)) => return Err(OrderValidationError::QtyAndNotional),
+            (None, None) => return Err(OrderValidationError::MissingQuantity),
+            (Some(qty), None) => {
+                ensure_positive("qty", qty)?;
+                qty.fract() != 0.0
+            }
+            (None, Some(notional)) => {
+                ensure_positive("notional", notional)?;
+                true
+            }
+        };
+
+        let needs_limit = matches!(order_type, OrderType::Limit | OrderType::StopLimit);
+        let needs_stop = matches!(order_type, OrderType::Stop | OrderType::StopLimit);
+        ensure_price(order_type, "limit_price", self.limit_price, needs_limit)?;
+        ensure_price(order_type, "stop_price", self.stop_price, needs_stop)?;
+
+        match (order_type, self.trail_price, self.trail_percent) {
+            (OrderType::TrailingStop, Some(price), None) => ensure_positive("trail_price", price)?,
+            (OrderType::TrailingStop, None, Some(percent)) => {
+                ensure_positive("trail_percent", percent)?;
+                if percent > 100.0 {
+                    return Err(OrderValidationError::InvalidTrail);
+                }
+            }
+            (OrderType::TrailingStop, _, _) => return Err(OrderValidationError::InvalidTrail),
+            (_, None, None) => {}
+            (_, Some(_), _) => {
+                return Err(OrderValidationError::UnexpectedPrice {
+                    order_type: order_type.clone(),
+                    field: "trail_price",
+                })
+            }
+            (_, None, Some(_)) => {
+                return Err(OrderValidationError::UnexpectedPrice {
+                    order_type: order_type.clone(),
+                    field: "trail_percent",
+                })
+            }
+        }
+
+        let auction_only = matches!(time_in_force, TimeInForce::OPG | TimeInForce::CLS);
+        let day_or_gtc = matches!(time_in_force, TimeInForce::Day | TimeInForce::GTC);
+        let tif_allowed = match order_type {
+            OrderType::Market | OrderType::Limit => true,
+            OrderType::TrailingStop => day_or_gtc,
+            OrderType::Stop | OrderType::StopLimit => !auction_only,
+        };
+        if !tif_allowed {
+            return Err(OrderValidationError::InvalidTimeInForce {
+                order_type: order_type.clone(),
+                time_in_force: time_in_force.clone(),
+            });
+        }
+
+        if self.extended_hours == Some(true)
+            && (*order_type != OrderType::Limit || *time_in_force != TimeInForce::Day)
+        {
+            return Err(OrderValidationError::ExtendedHoursRequiresLimitDay);
+        }
+
+        if is_fractional && (*order_type != OrderType::Market || *time_in_force != TimeInForce::Day)
+        {
+            return Err(OrderValidationError::FractionalRequiresMarketDay);
+        }
+
+        if let Some(client_order_id) = &self.client_order_id {
+            if client_order_id.len() > MAX_CLIENT_ORDER_ID_LEN {
+                return Err(OrderValidationError::ClientOrderIdTooLong(
+                    client_order_id.len(),
+                ));
+            }
+        }
+
+        self.validate_order_class()
+    }
+
+    /// checks the legs of an mleg order, which carry the symbols and sides of the order.
+    fn validate_legs(&self) -> Result<(), OrderValidationError> {
+        let legs = self.legs.as_deref().unwrap_or_default();
+        if legs.is_empty() {
+            return Err(OrderValidationError::InvalidAdvancedOrder(
+                "mleg orders need legs",
+            ));
+        }
+        if self.symbol.is_some() || self.side.is_some() {
+            return Err(OrderValidationError::InvalidAdvancedOrder(
+                "mleg orders take their symbols and sides from their legs",
+            ));
+        }
+        for leg in legs {
+            ensure_positive("legs.ratio_qty", leg.ratio_qty)?;
+        }
+        Ok(())
+    }
+
+    /// checks the take profit and stop loss legs of bracket, oco and oto orders
+    /// and the legs of mleg orders.
+    fn validate_order_class(&self) -> Result<(), OrderValidationError> {
+        let order_class = self.order_class.as_ref().unwrap_or(&OrderClass::Simple);
+        let (take_profit, stop_loss) = (self.take_profit.as_ref(), self.stop_loss.as_ref());
+
+        match order_class {
+            OrderClass::Simple => return Ok(()),
+            OrderClass::Mleg => return self.validate_legs(),
+            OrderClass::Bracket if take_profit.is_none() || stop_loss.is_none() => {
+                return Err(OrderValidationError::InvalidAdvancedOrder(
+                    "bracket orders need both take_profit and stop_loss",
+                ))
+            }
+            OrderClass::Oco if take_profit.is_none() || stop_loss.is_none() => {
+                return Err(OrderValidationError::InvalidAdvancedOrder(
+                    "oco orders need both take_profit and stop_loss",
+                ))
+            }
+            OrderClass::Oto if take_profit.is_some() == stop_loss.is_some() => {
+                return Err(OrderValidationError::InvalidAdvancedOrder(
+                    "oto orders need exactly one of take_profit or stop_loss",
+                ))
+            }
+            _ => {}
+        }
+
+        if !matches!(self.time_in_force, TimeInForce::Day | TimeInForce::GTC) {
+            return Err(OrderValidationError::InvalidTimeInForce {
+                order_type: self.order_type.clone(),
+                time_in_force: self.time_in_force.clone(),
+            });
+        }
+        if self.extended_hours == Some(true) {
+            return Err(OrderValidationError::InvalidAdvancedOrder(
+                "advanced orders cannot trade in extended hours",
+            ));
+        }
+
+        if let Some(take_profit) = take_profit {
+            ensure_positive("take_profit.limit_price", take_profit.limit_price)?;
+        }
+        if let Some(stop_loss) = stop_loss {
+            ensure_positive("stop_loss.stop_price", stop_loss.stop_price)?;
+            if let Some(limit_price) = stop_loss.limit_price {
+                ensure_positive("stop_loss.limit_price", limit_price)?;
+            }
+        }
+
+        if let (Some(take_profit), Some(stop_loss)) = (take_profit, stop_loss) {
+            let ordered = match self.side {
+                Some(OrderSide::Sell) => take_profit.limit_price < stop_loss.stop_price,
+                _ => take_profit.limit_price > stop_loss.stop_price,
+            };
+            if !ordered {
+                return Err(OrderValidationError::InvalidAdvancedOrder(
+                    "take_profit.limit_price must be on the profitable side of stop_loss.stop_price",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Builder state before a side has been chosen.
+#[derive(Debug, Clone)]
+pub struct NoSide;
+
+/// Builder state before a quantity or notional amount has been chosen.
+#[derive(Debug, Clone)]
+pub struct NoAmount;
+
+/// Builder state before a time in force has been chosen.
+#[derive(Debug, Clone)]
+pub struct NoTimeInForce;
+
+/// # Amount
+/// How much of the asset to trade. Holding one of the two makes
+/// sending both `qty` and `notional` unrepresentable.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Amount {
+    Qty(f64),
+    Notional(f64),
+}
+
+/// # OrderRequestBuilder
+/// Builds an [`OrderRequest`] one step at a time. The side, amount and time in force
+/// are tracked in the type, so `build` is only available once all three are set.
+/// Prices required by the order type are taken by the constructors on [`OrderRequest`].
+///
+/// ```
+/// use alpaca_types::orders::OrderRequest;
+///
+/// let order = OrderRequest::market("AAPL").buy().qty(10).day().build();
+/// assert!(order.is_ok());
+///
+/// let order = OrderRequest::limit("AAPL", 150.0).sell().qty(5).gtc().build();
+/// assert!(order.is_ok());
+/// ```
+#[derive(Debug, Clone)]
+pub struct OrderRequestBuilder<Side, Amt, Tif> {
+    symbol: String,
+    order_type: OrderType,
+    side: Side,
+    amount: Amt,
+    time_in_force: Tif,
+    limit_price: Option<f64>,
+    stop_price: Option<f64>,
+    trail_price: Option<f64>,
+    trail_percent: Option<f64>,
+    extended_hours: Option<bool>,
+    client_order_id: Option<String>,
+    order_class: Option<OrderClass>,
+    take_profit: Option<TakeProfit>,
+    stop_loss: Option<StopLoss>,
+    position_intent: Option<PositionIntent>,
+}
+
+impl OrderRequestBuilder<NoSide, NoAmount, NoTimeInForce> {
+    fn new(symbol: impl Into<String>, order_type: OrderType) -> Self {
+        Self {
+            symbol: symbol.into(),
+            order_type,
+            side: NoSide,
+            amount: NoAmount,
+            time_in_force: NoTimeInForce,
+            limit_price: None,
+            stop_price: None,
+            trail_price: None,
+            trail_percent: None,
+            extended_hours: None,
+            client_order_id: None,
+            order_class: None,
+            take_profit: None,
+            stop_loss: None,
+            position_intent: None,
+        }
+    }
+}
+
+impl<Side, Amt, Tif> OrderRequestBuilder<Side, Amt, Tif> {
+    /// moves every field across while swapping the typestates.
+    fn transition<S, A, T>(
+        self,
+        states: impl FnOnce(Side, Amt, Tif) -> (S, A, T),
+    ) -> OrderRequestBuilder<S, A, T> {
+        let (side, amount, time_in_force) = states(self.side, self.amount, self.time_in_force);
+        OrderRequestBuilder {
+            symbol: self.symbol,
+            order_type: self.order_type,
+            side,
+            amount,
+            time_in_force,
+            limit_price: self.limit_price,
+            stop_price: self.stop_price,
+            trail_price: self.trail_price,
+            trail_percent: self.trail_percent,
+            extended_hours: self.extended_hours,
+            client_order_id: self.client_order_id,
+            order_class: self.order_class,
+            take_profit: self.take_profit,
+            stop_loss: self.stop_loss,
+            position_intent: self.position_intent,
+        }
+    }
+
+    /// sets a custom id for the order.
+    pub fn client_order_id(mut self, client_order_id: impl Into<String>) -> Self {
+        self.client_order_id = Some(client_order_id.into());
+        self
+    }
+
+    /// allows the order to fill in pre-market and after-hours sessions.
+    pub fn extended_hours(mut self) -> Self {
+        self.extended_hours = Some(true);
+        self
+    }
+
+    pub fn position_intent(mut self, position_intent: PositionIntent) -> Self {
+        self.position_intent = Some(position_intent);
+        self
+    }
+
+    /// turns the order into a bracket order with both exit legs.
+    pub fn bracket(mut self, take_profit: TakeProfit, stop_loss: StopLoss) -> Self {
+        self.order_class = Some(OrderClass::Bracket);
+        self.take_profit = Some(take_profit);
+        self.stop_loss = Some(stop_loss);
+        self
+    }
+
+    /// turns the order into a one-cancels-other exit order.
+    pub fn oco(mut self, take_profit: TakeProfit, stop_loss: StopLoss) -> Self {
+        self.order_class = Some(OrderClass::Oco);
+        self.take_profit = Some(take_profit);
+        self.stop_loss = Some(stop_loss);
+        self
+    }
+
+    /// turns the order into a one-triggers-other order with a take profit leg.
+    pub fn oto_take_profit(mut self, take_profit: TakeProfit) -> Self {
+        self.order_class = Some(OrderClass::Oto);
+        self.take_profit = Some(take_profit);
+        self.stop_loss = None;
+        self
+    }
+
+    /// turns the order into a one-triggers-other order with a stop loss leg.
+    pub fn oto_stop_loss(mut self, stop_loss: StopLoss) -> Self {
+        self.order_class = Some(OrderClass::Oto);
+        self.take_profit = None;
+        self.stop_loss = Some(stop_loss);
+        self
+    }
+}
+
+impl<Amt, Tif> OrderRequestBuilder<NoSide, Amt, Tif> {
+    pub fn side(self, side: OrderSide) -> OrderRequestBuilder<OrderSide, Amt, Tif> {
+        self.transition(|_, amount, time_in_force| (side, amount, time_in_force))
+    }
+
+    pub fn buy(self) -> OrderRequestBuilder<OrderSide, Amt, Tif> {
+        self.side(OrderSide::Buy)
+    }
+
+    pub fn sell(self) -> OrderRequestBuilder<OrderSide, Amt, Tif> {
+        self.side(OrderSide::Sell)
+    }
+}
+
+impl<Side, Tif> OrderRequestBuilder<Side, NoAmount, Tif> {
+    /// the number of shares or contracts to trade. Fractional amounts are allowed.
+    pub fn qty(self, qty: impl Into<f64>) -> OrderRequestBuilder<Side, Amount, Tif> {
+        self.transition(|side, _, time_in_force| (side, Amount::Qty(qty.into()), time_in_force))
+    }
+
+    /// the dollar amount to trade.
+    pub fn notional(self, notional: impl Into<f64>) -> OrderRequestBuilder<Side, Amount, Tif> {
+        self.transition(|side, _, time_in_force| {
+            (side, Amount::Notional(notional.into()), time_in_force)
+        })
+    }
+}
+
+impl<Side, Amt> OrderRequestBuilder<Side, Amt, NoTimeInForce> {
+    pub fn time_in_force(
+        self,
+        time_in_force: TimeInForce,
+    ) -> OrderRequestBuilder<Side, Amt, TimeInForce> {
+        self.transition(|side, amount, _| (side, amount, time_in_force))
+    }
+
+    pub fn day(self) -> OrderRequestBuilder<Side, Amt, TimeInForce> {
+        self.time_in_force(TimeInForce::Day)
+    }
+
+    pub fn gtc(self) -> OrderRequestBuilder<Side, Amt, TimeInForce> {
+        self.time_in_force(TimeInForce::GTC)
+    }
+
+    pub fn opg(self) -> OrderRequestBuilder<Side, Amt, TimeInForce> {
+        self.time_in_force(TimeInForce::OPG)
+    }
+
+    pub fn cls(self) -> OrderRequestBuilder<Side, Amt, TimeInForce> {
+        self.time_in_force(TimeInForce::CLS)
+    }
+
+    pub fn ioc(self) -> OrderRequestBuilder<Side, Amt, TimeInForce> {
+        self.time_in_force(TimeInForce::IOC)
+    }
+
+    pub fn fok(self) -> OrderRequestBuilder<Side, Amt, TimeInForce> {
+        self.time_in_force(TimeInForce::FOK)
+    }
+}
+
+impl OrderRequestBuilder<OrderSide, Amount, TimeInForce> {
+    /// assembles the order and validates it with [`OrderRequest::validate`].
+    pub fn build(self) -> Result<OrderRequest, OrderValidationError> {
+        let (qty, notional) = match self.amount {
+            Amount::Qty(qty) => (Some(qty), None),
+            Amount::Notional(notional) => (None, Some(notional)),
+        };
+
+        let order = OrderRequest {
+            symbol: Some(self.symbol),
+            qty,
+            notional,
+            side: Some(self.side),
+            order_type: self.order_type,
+            time_in_force: self.time_in_force,
+            limit_price: self.limit_price,
+            stop_price: self.stop_price,
+            trail_price: self.trail_price,
+            trail_percent: self.trail_percent,
+            extended_hours: self.extended_hours,
+            client_order_id: self.client_order_id,
+            order_class: self.order_class,
+            take_profit: self.take_profit,
+            stop_loss: self.stop_loss,
+            position_intent: self.position_intent,
+            legs: None,
+        };
+        order.validate()?;
+        Ok(order)
+    }
+}
+
+/// # Trail
+/// The trailing amount of a `trailing_stop` order.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Trail {
+    /// a dollar offset from the high water mark.
+    Price(f64),
+    /// a percent offset from the high water mark.
+    Percent(f64),
+}
+
+impl OrderRequest {
+    /// starts building a market order.
+    pub fn market(
+        symbol: impl Into<String>,
+    ) -> OrderRequestBuilder<NoSide, NoAmount, NoTimeInForce> {
+        OrderRequestBuilder::new(symbol, OrderType::Market)
+    }
+
+    /// starts building a limit order.
+    pub fn limit(
+        symbol: impl Into<String>,
+        limit_price: f64,
+    ) -> OrderRequestBuilder<NoSide, NoAmount, NoTimeInForce> {
+        let mut builder = OrderRequestBuilder::new(symbol, OrderType::Limit);
+        builder.limit_price = Some(limit_price);
+        builder
+    }
+
+    /// starts building a stop order.
+    pub fn stop(
+        symbol: impl Into<String>,
+        stop_price: f64,
+    ) -> OrderRequestBuilder<NoSide, NoAmount, NoTimeInForce> {
+        let mut builder = OrderRequestBuilder::new(symbol, OrderType::Stop);
+        builder.stop_price = Some(stop_price);
+        builder
+    }
+
+    /// starts building a stop limit order.
+    pub fn stop_limit(
+        symbol: impl Into<String>,
+        stop_price: f64,
+        limit_price: f64,
+    ) -> OrderRequestBuilder<NoSide, NoAmount, NoTimeInForce> {
+        let mut builder = OrderRequestBuilder::new(symbol, OrderType::StopLimit);
+        builder.stop_price = Some(stop_price);
+        builder.limit_price = Some(limit_price);
+        builder
+    }
+
+    /// starts building a trailing stop order.
+    pub fn trailing_stop(
+        symbol: impl Into<String>,
+        trail: Trail,
+    ) -> OrderRequestBuilder<NoSide, NoAmount, NoTimeInForce> {
+        let mut builder = OrderRequestBuilder::new(symbol, OrderType::TrailingStop);
+        match trail {
+            Trail::Price(price) => builder.trail_price = Some(price),
+            Trail::Percent(percent) => builder.trail_percent = Some(percent),
+        }
+        builder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OrderValidationError, Trail};
+    use crate::orders::{
+        OrderClass, OrderRequest, OrderSide, OrderType, StopLoss, TakeProfit, TimeInForce,
+    };
+
+    #[test]
+    fn market_order_builds() {
+        let order = OrderRequest::market("AAPL")
+            .buy()
+            .qty(10)
+            .day()
+            .build()
+            .expect("failed to build market order");
+
+        let wanted =
+            r#"{"symbol":"AAPL","qty":"10","side":"buy","type":"market","time_in_force":"day"}"#;
+        let got = serde_json::to_string(&order).expect("failed to serialize order");
+        assert_eq!(wanted, got);
+    }
+
+    #[test]
+    fn limit_order_builds_in_any_step_order() {
+        let order = OrderRequest::limit("AAPL", 150.0)
+            .gtc()
+            .qty(5)
+            .client_order_id("my_order")
+            .sell()
+            .build()
+            .expect("failed to build limit order");
+
+        assert_eq!(order.order_type, OrderType::Limit);
+        assert_eq!(order.limit_price, Some(150.0));
+        assert_eq!(order.side, Some(OrderSide::Sell));
+        assert_eq!(order.time_in_force, TimeInForce::GTC);
+    }
+
+    #[test]
+    fn extended_hours_requires_limit_day() {
+        let got = OrderRequest::limit("AAPL", 150.0)
+            .buy()
+            .qty(1)
+            .gtc()
+            .extended_hours()
+            .build();
+        assert_eq!(
+            got,
+            Err(OrderValidationError::ExtendedHoursRequiresLimitDay)
+        );
+
+        let got = OrderRequest::limit("AAPL", 150.0)
+            .buy()
+            .qty(1)
+            .day()
+            .extended_hours()
+            .build();
+        assert!(got.is_ok());
+    }
+
+    #[test]
+    fn fractional_requires_market_day() {
+        let got = OrderRequest::market("AAPL").buy().qty(0.5).gtc().build();
+        assert_eq!(got, Err(OrderValidationError::FractionalRequiresMarketDay));
+
+        let got = OrderRequest::limit("AAPL", 150.0)
+            .buy()
+            .notional(100)
+            .day()
+            .build();
+        assert_eq!(got, Err(OrderValidationError::FractionalRequiresMarketDay));
+
+        let got = OrderRequest::market("AAPL")
+            .buy()
+            .notional(100)
+            .day()
+            .build();
+        assert!(got.is_ok());
+    }
+
+    #[test]
+    fn trailing_stop_validates_trail() {
+        let got = OrderRequest::trailing_stop("AAPL", Trail::Percent(150.0))
+            .sell()
+            .qty(1)
+            .day()
+            .build();
+        assert_eq!(got, Err(OrderValidationError::InvalidTrail));
+
+        let got = OrderRequest::trailing_stop("AAPL", Trail::Price(2.5))
+            .sell()
+            .qty(1)
+            .opg()
+            .build();
+        assert!(matches!(
+            got,
+            Err(OrderValidationError::InvalidTimeInForce { .. })
+        ));
+    }
+
+    #[test]
+    fn non_positive_prices_are_rejected() {
+        let got = OrderRequest::stop_limit("AAPL", 10.0, -1.0)
+            .sell()
+            .qty(1)
+            .day()
+            .build();
+        assert_eq!(
+            got,
+            Err(OrderValidationError::NotPositive {
+                field: "limit_price",
+                value: -1.0
+            })
+        );
+    }
+
+    #[test]
+    fn bracket_legs_must_be_ordered() {
+        let take_profit = TakeProfit { limit_price: 90.0 };
+        let stop_loss = StopLoss {
+            stop_price: 95.0,
+            limit_price: None,
+        };
+
+        let got = OrderRequest::market("SPY")
+            .buy()
+            .qty(100)
+            .gtc()
+            .bracket(take_profit.clone(), stop_loss.clone())
+            .build();
+        assert!(matches!(
+            got,
+            Err(OrderValidationError::InvalidAdvancedOrder(_))
+        ));
+
+        let order = OrderRequest::market("SPY")
+            .sell()
+            .qty(100)
+            .gtc()
+            .bracket(take_profit, stop_loss)
+            .build()
+            .expect("failed to build bracket order");
+        assert_eq!(order.order_class, Some(OrderClass::Bracket));
+    }
+
+    #[test]
+    fn struct_literal_orders_validate() {
+        let order = OrderRequest {
+            symbol: Some("AAPL".to_string()),
+            qty: Some(1.0),
+            notional: Some(100.0),
+            side: Some(OrderSide::Buy),
+            order_type: OrderType::Limit,
+            time_in_force: TimeInForce::Day,
+            limit_price: None,
+            stop_price: None,
+            trail_price: None,
+            trail_percent: None,
+            extended_hours: None,
+            client_order_id: None,
+            order_class: None,
+            take_profit: None,
+            stop_loss: None,
+            position_intent: None,
+            legs: None,
+        };
+        assert_eq!(order.validate(), Err(OrderValidationError::QtyAndNotional));
+
+        let order = OrderRequest {
+            notional: None,
+            ..order
+        };
+        assert_eq!(
+            order.validate(),
+            Err(OrderValidationError::MissingPrice {
+                order_type: OrderType::Limit,
+                field: "limit_price"
+            })
+        );
+
+        let order = OrderRequest {
+            side: None,
+            limit_price: Some(150.0),
+            ..order
+        };
+        assert_eq!(
+            order.validate(),
+            Err(OrderValidationError::MissingField("side"))
+        );
+
+        // mleg orders take their symbols and sides from their legs.
+        let order = OrderRequest {
+            symbol: None,
+            order_class: Some(OrderClass::Mleg),
+            legs: Some(vec![]),
+            ..order
+        };
+        assert_eq!(
+            order.validate(),
+            Err(OrderValidationError::InvalidAdvancedOrder(
+                "mleg orders need legs"
+            ))
+        );
+    }
+}
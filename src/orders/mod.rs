@@ -0,0 +1,953 @@
+mod builder;
+mod query;
+mod replace;
+mod status;
+mod tracker;
+mod trade_update;
+
+pub use builder::{
+    Amount, NoAmount, NoSide, NoTimeInForce, OrderRequestBuilder, OrderValidationError, Trail,
+};
+pub use query::{CancelAllOrdersResponse, ListOrdersQuery, OrderQueryStatus, SortDirection};
+pub use replace::{ReplaceDiff, ReplaceOrderRequest};
+pub use status::{InvalidTransition, OrderEvent};
+pub use tracker::{Fill, OrderTracker, TrackedOrder};
+pub use trade_update::TradeUpdate;
+
+use crate::errors::ApiError;
+use crate::{f64_from_opt_string, serialize_opt_qty, serialize_qty, string_to_f64};
+use std::fmt::Display;
+
+use serde::Deserialize;
+use serde::Serialize;
+use time::serde::rfc3339;
+
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "ts")]
+use ts_rs::TS;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum OrderSide {
+    Buy,
+    Sell,
+}
+
+impl From<String> for OrderSide {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "buy" => OrderSide::Buy,
+            "sell" => OrderSide::Sell,
+            other => panic!("Unknown string {other}"),
+        }
+    }
+}
+
+impl Display for OrderSide {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OrderSide::Buy => write!(f, "buy"),
+            OrderSide::Sell => write!(f, "sell"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum OrderType {
+    Market,
+    Limit,
+    Stop,
+    StopLimit,
+    TrailingStop,
+}
+
+impl From<String> for OrderType {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "market" => OrderType::Market,
+            "limit" => OrderType::Limit,
+            "stop" => OrderType::Stop,
+            "stop_limit" => OrderType::StopLimit,
+            "trailing_stop" => OrderType::TrailingStop,
+            other => panic!("Unknown string {other}"),
+        }
+    }
+}
+
+impl Display for OrderType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OrderType::Market => write!(f, "market"),
+            OrderType::Limit => write!(f, "limit"),
+            OrderType::Stop => write!(f, "stop"),
+            OrderType::StopLimit => write!(f, "stop_limit"),
+            OrderType::TrailingStop => write!(f, "trailing_stop"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum TimeInForce {
+    Day,
+    GTC,
+    /// Market on open, only eligible during the opening auction.
+    OPG,
+    /// Market on close, only eligible during the closing auction.
+    CLS,
+    /// Immediate or cancel.
+    IOC,
+    /// Fill or kill.
+    FOK,
+}
+
+impl Display for TimeInForce {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimeInForce::Day => write!(f, "day"),
+            TimeInForce::GTC => write!(f, "gtc"),
+            TimeInForce::OPG => write!(f, "opg"),
+            TimeInForce::CLS => write!(f, "cls"),
+            TimeInForce::IOC => write!(f, "ioc"),
+            TimeInForce::FOK => write!(f, "fok"),
+        }
+    }
+}
+
+/// # OrderClass
+/// The class of an order. Defaults to `simple` on the server when omitted.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum OrderClass {
+    Simple,
+    Bracket,
+    Oco,
+    Oto,
+    Mleg,
+}
+
+impl Display for OrderClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OrderClass::Simple => write!(f, "simple"),
+            OrderClass::Bracket => write!(f, "bracket"),
+            OrderClass::Oco => write!(f, "oco"),
+            OrderClass::Oto => write!(f, "oto"),
+            OrderClass::Mleg => write!(f, "mleg"),
+        }
+    }
+}
+
+/// # PositionIntent
+/// Represents the desired position strategy of an order,
+/// mostly used to open or close option positions.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum PositionIntent {
+    BuyToOpen,
+    BuyToClose,
+    SellToOpen,
+    SellToClose,
+}
+
+impl From<String> for PositionIntent {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "buy_to_open" => PositionIntent::BuyToOpen,
+            "buy_to_close" => PositionIntent::BuyToClose,
+            "sell_to_open" => PositionIntent::SellToOpen,
+            "sell_to_close" => PositionIntent::SellToClose,
+            other => panic!("Unknown string in position intent: {other}"),
+        }
+    }
+}
+
+impl Display for PositionIntent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PositionIntent::BuyToOpen => write!(f, "buy_to_open"),
+            PositionIntent::BuyToClose => write!(f, "buy_to_close"),
+            PositionIntent::SellToOpen => write!(f, "sell_to_open"),
+            PositionIntent::SellToClose => write!(f, "sell_to_close"),
+        }
+    }
+}
+
+/// # TakeProfit
+/// The take profit leg of an advanced (bracket, oco, oto) order.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct TakeProfit {
+    #[serde(serialize_with = "serialize_qty", deserialize_with = "string_to_f64")]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::schema::NumberString"))]
+    pub limit_price: f64,
+}
+
+/// # StopLoss
+/// The stop loss leg of an advanced (bracket, oco, oto) order.
+/// * `stop_price`: the price that triggers the stop loss.
+/// * `limit_price`: if given, the stop loss becomes a stop limit order.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct StopLoss {
+    #[serde(serialize_with = "serialize_qty", deserialize_with = "string_to_f64")]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::schema::NumberString"))]
+    pub stop_price: f64,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub limit_price: Option<f64>,
+}
+
+/// # OrderLegRequest
+/// A single leg of a multi-leg (`mleg`) options order.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct OrderLegRequest {
+    pub symbol: String,
+    #[serde(serialize_with = "serialize_qty", deserialize_with = "string_to_f64")]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::schema::NumberString"))]
+    pub ratio_qty: f64,
+    pub side: OrderSide,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub position_intent: Option<PositionIntent>,
+}
+
+/// # OrderRequest
+/// the structure of data to send an order to Alpacas API.
+/// Only one of `qty` or `notional` should be set.
+/// Optional fields are left out of the payload when `None`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub struct OrderRequest {
+    /// required by every order class but `mleg`, whose legs carry the symbols.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub symbol: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub qty: Option<f64>,
+    /// dollar amount to trade. Cannot be used together with `qty`.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub notional: Option<f64>,
+    /// required by every order class but `mleg`, whose legs carry the sides.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub side: Option<OrderSide>,
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    pub time_in_force: TimeInForce,
+    /// required if type is `limit` or `stop_limit`.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub limit_price: Option<f64>,
+    /// required if type is `stop` or `stop_limit`.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub stop_price: Option<f64>,
+    /// used with `trailing_stop`. Only one of `trail_price` or `trail_percent` should be set.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub trail_price: Option<f64>,
+    /// used with `trailing_stop`. Only one of `trail_price` or `trail_percent` should be set.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub trail_percent: Option<f64>,
+    /// only works with `limit` orders and a `day` time in force.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub extended_hours: Option<bool>,
+    /// a unique identifier for the order, generated by the server if not provided.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub client_order_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub order_class: Option<OrderClass>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub take_profit: Option<TakeProfit>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub stop_loss: Option<StopLoss>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub position_intent: Option<PositionIntent>,
+    /// legs of a multi-leg options order. Only used with the `mleg` order class.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub legs: Option<Vec<OrderLegRequest>>,
+}
+
+/// # AssetClass
+/// Serves to identify the nature of the financial instrument,
+/// with options including "us_equity" for U.S. equities,
+/// "us_option" for U.S. options, and "crypto" for cryptocurrencies.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum AssetClass {
+    UsEquity,
+    UsOption,
+    Crypto,
+}
+
+impl From<String> for AssetClass {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "crypto" => AssetClass::Crypto,
+            "us_equity" => AssetClass::UsEquity,
+            "us_option" => AssetClass::UsOption,
+            other => panic!("Unknown string in asset class: {other}"),
+        }
+    }
+}
+
+/// # OrderResponse
+/// the structure of data in response to an order being sent.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+pub struct OrderResponse {
+    pub id: Uuid,
+    pub client_order_id: String,
+
+    #[serde(with = "rfc3339")]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::schema::DateTime"))]
+    pub created_at: OffsetDateTime,
+
+    #[serde(with = "rfc3339")]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::schema::DateTime"))]
+    pub updated_at: OffsetDateTime,
+
+    #[serde(with = "rfc3339")]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::schema::DateTime"))]
+    pub submitted_at: OffsetDateTime,
+
+    #[serde(with = "rfc3339::option")]
+    #[cfg_attr(feature = "ts", ts(type = "string | null"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::DateTime>")
+    )]
+    pub filled_at: Option<OffsetDateTime>,
+
+    #[serde(with = "rfc3339::option")]
+    #[cfg_attr(feature = "ts", ts(type = "string | null"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::DateTime>")
+    )]
+    pub expired_at: Option<OffsetDateTime>,
+
+    #[serde(with = "rfc3339::option")]
+    #[cfg_attr(feature = "ts", ts(type = "string | null"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::DateTime>")
+    )]
+    pub canceled_at: Option<OffsetDateTime>,
+
+    #[serde(with = "rfc3339::option")]
+    #[cfg_attr(feature = "ts", ts(type = "string | null"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::DateTime>")
+    )]
+    pub failed_at: Option<OffsetDateTime>,
+
+    #[serde(with = "rfc3339::option")]
+    #[cfg_attr(feature = "ts", ts(type = "string | null"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::DateTime>")
+    )]
+    pub replaced_at: Option<OffsetDateTime>,
+
+    /// The order ID that this order was replaced by
+    pub replaced_by: Option<String>,
+
+    /// The order ID that this order replaces
+    pub replaces: Option<String>,
+
+    /// Asset ID (For options this represents the option contract ID)
+    pub asset_id: Uuid,
+
+    ///Asset symbol
+    pub symbol: String,
+
+    pub asset_class: AssetClass,
+
+    /// Ordered notional amount. If entered, qty will be null. Can take up to 9 decimal points.
+    pub notional: Option<String>,
+
+    /// Ordered quantity. If entered, notional will be null. Can take up to 9 decimal points.
+    #[serde(
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(type = "string | null"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub qty: Option<f64>,
+
+    /// quantity filled in this order.
+    pub filled_qty: String,
+
+    /// the average price this order filled at.
+    #[serde(
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(type = "string | null"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub filled_avg_price: Option<f64>,
+
+    /// simple bracket oco oto
+    pub order_class: String,
+
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+
+    #[serde(rename = "side")]
+    pub order_side: OrderSide,
+
+    pub time_in_force: TimeInForce,
+
+    #[serde(
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(type = "string | null"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub limit_price: Option<f64>,
+
+    #[serde(
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(type = "string | null"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub stop_price: Option<f64>,
+
+    #[serde(rename = "status")]
+    pub status: OrderStatus,
+
+    pub extended_hours: bool,
+
+    /// the legs of a bracket, oco, oto or mleg order, when requested with `nested`.
+    #[serde(default)]
+    pub legs: Option<Vec<OrderResponse>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub position_intent: Option<PositionIntent>,
+
+    /// the ratio of a leg to the whole order, only set on the legs of mleg orders.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub ratio_qty: Option<String>,
+
+    /// a deprecated copy of `type` that Alpaca still sends.
+    #[serde(
+        rename = "order_type",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[cfg_attr(feature = "ts", ts(rename = "order_type", optional))]
+    pub deprecated_order_type: Option<OrderType>,
+
+    #[serde(default)]
+    pub trail_percent: Option<serde_json::Value>,
+
+    #[serde(default)]
+    pub trail_price: Option<serde_json::Value>,
+
+    #[serde(default)]
+    pub hwm: Option<serde_json::Value>,
+
+    #[serde(default)]
+    pub subtag: Option<serde_json::Value>,
+
+    #[serde(default)]
+    pub source: Option<serde_json::Value>,
+    /// fields Alpaca sent that this type does not know yet.
+    #[cfg(feature = "extra_fields")]
+    #[cfg_attr(not(feature = "deny_unknown_fields"), serde(flatten))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(skip))]
+    #[cfg_attr(feature = "ts", ts(skip))]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
+}
+
+/// # OrderResult
+/// the body of a single entry of a multi-status (207) response, such as closing all
+/// positions or canceling all orders. Successful entries hold the order,
+/// failed ones hold the error Alpaca returned for it.
+/// Prefer [`OrderResult::from_status`] when the status is known; deserializing without it
+/// tries an order first and falls back to an error.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(untagged)]
+pub enum OrderResult {
+    Order(Box<OrderResponse>),
+    Error(ApiError),
+}
+
+impl OrderResult {
+    /// parses `body` as an order for 2xx statuses and as an error otherwise.
+    pub fn from_status(status: u16, body: serde_json::Value) -> Result<Self, serde_json::Error> {
+        if (200..300).contains(&status) {
+            Ok(OrderResult::Order(Box::new(serde_json::from_value(body)?)))
+        } else {
+            Ok(OrderResult::Error(serde_json::from_value(body)?))
+        }
+    }
+
+    pub fn is_ok(&self) -> bool {
+        matches!(self, OrderResult::Order(_))
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn into_result(self) -> Result<OrderResponse, ApiError> {
+        match self {
+            OrderResult::Order(order) => Ok(*order),
+            OrderResult::Error(error) => Err(error),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum OrderStatus {
+    New,
+    PartiallyFilled,
+    Filled,
+    DoneForDay,
+    Canceled,
+    Expired,
+    Replaced,
+    PendingCancel,
+    PendingReplace,
+    Accepted,
+    PendingNew,
+    AcceptedForBidding,
+    Stopped,
+    Rejected,
+    Suspended,
+    Calculated,
+}
+
+impl From<&str> for OrderStatus {
+    fn from(status: &str) -> Self {
+        match status {
+            "new" => OrderStatus::New,
+            "partially_filled" => OrderStatus::PartiallyFilled,
+            "filled" => OrderStatus::Filled,
+            "done_for_day" => OrderStatus::DoneForDay,
+            "canceled" => OrderStatus::Canceled,
+            "expired" => OrderStatus::Expired,
+            "replaced" => OrderStatus::Replaced,
+            "pending_cancel" => OrderStatus::PendingCancel,
+            "pending_replace" => OrderStatus::PendingReplace,
+            "accepted" => OrderStatus::Accepted,
+            "pending_new" => OrderStatus::PendingNew,
+            "accepted_for_bidding" => OrderStatus::AcceptedForBidding,
+            "stopped" => OrderStatus::Stopped,
+            "rejected" => OrderStatus::Rejected,
+            "suspended" => OrderStatus::Suspended,
+            "calculated" => OrderStatus::Calculated,
+            _ => panic!("Invalid order status: {}", status),
+        }
+    }
+}
+
+impl Display for OrderStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OrderStatus::New => write!(f, "new"),
+            OrderStatus::PartiallyFilled => write!(f, "partially_filled"),
+            OrderStatus::Filled => write!(f, "filled"),
+            OrderStatus::DoneForDay => write!(f, "done_for_day"),
+            OrderStatus::Canceled => write!(f, "canceled"),
+            OrderStatus::Expired => write!(f, "expired"),
+            OrderStatus::Replaced => write!(f, "replaced"),
+            OrderStatus::PendingCancel => write!(f, "pending_cancel"),
+            OrderStatus::PendingReplace => write!(f, "pending_replace"),
+            OrderStatus::Accepted => write!(f, "accepted"),
+            OrderStatus::PendingNew => write!(f, "pending_new"),
+            OrderStatus::AcceptedForBidding => write!(f, "accepted_for_bidding"),
+            OrderStatus::Stopped => write!(f, "stopped"),
+            OrderStatus::Rejected => write!(f, "rejected"),
+            OrderStatus::Suspended => write!(f, "suspended"),
+            OrderStatus::Calculated => write!(f, "calculated"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::orders::{OrderResponse, OrderType, TimeInForce};
+
+    use super::{OrderClass, OrderRequest, OrderSide, PositionIntent, StopLoss};
+
+    const ORDER: &str = r#"
+    {
+        "id": "7b08df51-c1ac-453c-99f9-323a5f075f0d",
+        "client_order_id": "5680c4bc-9ac1-4a12-a44c-df427ba53032",
+        "created_at": "2023-12-12T22:31:24.668464435Z",
+        "updated_at": "2023-12-12T22:31:24.668464435Z",
+        "submitted_at": "2023-12-12T22:31:24.577215743Z",
+        "filled_at": null,
+        "expired_at": null,
+        "canceled_at": null,
+        "failed_at": null,
+        "replaced_at": null,
+        "replaced_by": null,
+        "replaces": null,
+        "asset_id": "b0b6dd9d-8b9b-48a9-ba46-b9d54906e415",
+        "symbol": "AAPL",
+        "asset_class": "us_equity",
+        "notional": null,
+        "qty": "2",
+        "filled_qty": "0",
+        "filled_avg_price": null,
+        "order_class": "",
+        "order_type": "limit",
+        "type": "limit",
+        "side": "buy",
+        "time_in_force": "gtc",
+        "limit_price": "150",
+        "stop_price": null,
+        "status": "accepted",
+        "extended_hours": false,
+        "legs": null,
+        "trail_percent": null,
+        "trail_price": null,
+        "hwm": null,
+        "subtag": null,
+        "source": null
+    }
+    "#;
+
+    #[test]
+    fn test_parsing_order() {
+        let data = serde_json::from_str::<OrderResponse>(ORDER);
+        assert!(data.is_ok());
+    }
+
+    /// the order fixture with a field `OrderResponse` does not model.
+    #[cfg(any(feature = "extra_fields", feature = "deny_unknown_fields"))]
+    fn order_with_unknown_field() -> String {
+        ORDER.replace(
+            r#""source": null"#,
+            r#""source": null, "expires_at": "2023-12-13T21:00:00Z""#,
+        )
+    }
+
+    #[cfg(all(feature = "extra_fields", not(feature = "deny_unknown_fields")))]
+    #[test]
+    fn captures_unknown_fields() {
+        let order = serde_json::from_str::<OrderResponse>(&order_with_unknown_field())
+            .expect("failed to parse order");
+        assert_eq!(order.legs, None);
+        assert_eq!(
+            order.extra.get("expires_at"),
+            Some(&serde_json::json!("2023-12-13T21:00:00Z"))
+        );
+
+        let written = serde_json::to_value(&order).expect("failed to serialize order");
+        assert_eq!(written["expires_at"], "2023-12-13T21:00:00Z");
+    }
+
+    #[cfg(feature = "deny_unknown_fields")]
+    #[test]
+    fn rejects_unknown_fields() {
+        assert!(serde_json::from_str::<OrderResponse>(ORDER).is_ok());
+        assert!(serde_json::from_str::<OrderResponse>(&order_with_unknown_field()).is_err());
+    }
+
+    #[test]
+    fn order_request_serializes() {
+        let wanted =
+            r#"{"symbol":"PTON","qty":"10","side":"buy","type":"market","time_in_force":"day"}"#;
+
+        let new_order = OrderRequest {
+            qty: Some(10.0),
+            notional: None,
+            side: Some(OrderSide::Buy),
+            symbol: Some("PTON".to_string()),
+            order_type: OrderType::Market,
+            time_in_force: TimeInForce::Day,
+            limit_price: None,
+            stop_price: None,
+            trail_price: None,
+            trail_percent: None,
+            extended_hours: None,
+            client_order_id: None,
+            order_class: None,
+            take_profit: None,
+            stop_loss: None,
+            position_intent: None,
+            legs: None,
+        };
+        let got = serde_json::to_string(&new_order).expect("failed to serialize order");
+        assert!(wanted == got);
+    }
+
+    /// parses a documented payload, serializes it back and checks nothing changed.
+    fn assert_order_request_round_trips(payload: &str) -> OrderRequest {
+        let parsed =
+            serde_json::from_str::<OrderRequest>(payload).expect("failed to parse order request");
+        let got = serde_json::to_value(&parsed).expect("failed to serialize order request");
+        let wanted: serde_json::Value = serde_json::from_str(payload).expect("invalid json");
+        assert_eq!(wanted, got);
+        parsed
+    }
+
+    #[test]
+    fn extended_hours_limit_order_round_trips() {
+        let payload = r#"
+        {
+            "symbol": "AAPL",
+            "qty": "1",
+            "side": "buy",
+            "type": "limit",
+            "time_in_force": "day",
+            "limit_price": "150.25",
+            "extended_hours": true,
+            "client_order_id": "my_order_1"
+        }
+        "#;
+
+        let order = assert_order_request_round_trips(payload);
+        assert_eq!(order.limit_price, Some(150.25));
+        assert_eq!(order.extended_hours, Some(true));
+        assert_eq!(order.client_order_id.as_deref(), Some("my_order_1"));
+    }
+
+    #[test]
+    fn notional_order_round_trips() {
+        let payload = r#"
+        {
+            "symbol": "AAPL",
+            "notional": "500",
+            "side": "buy",
+            "type": "market",
+            "time_in_force": "day"
+        }
+        "#;
+
+        let order = assert_order_request_round_trips(payload);
+        assert_eq!(order.qty, None);
+        assert_eq!(order.notional, Some(500.0));
+    }
+
+    #[test]
+    fn bracket_order_round_trips() {
+        let payload = r#"
+        {
+            "side": "buy",
+            "symbol": "SPY",
+            "type": "market",
+            "qty": "100",
+            "time_in_force": "gtc",
+            "order_class": "bracket",
+            "take_profit": {
+                "limit_price": "301"
+            },
+            "stop_loss": {
+                "stop_price": "299",
+                "limit_price": "298.5"
+            }
+        }
+        "#;
+
+        let order = assert_order_request_round_trips(payload);
+        assert_eq!(order.order_class, Some(OrderClass::Bracket));
+        assert_eq!(
+            order.stop_loss,
+            Some(StopLoss {
+                stop_price: 299.0,
+                limit_price: Some(298.5)
+            })
+        );
+    }
+
+    #[test]
+    fn trailing_stop_order_round_trips() {
+        let payload = r#"
+        {
+            "side": "sell",
+            "symbol": "AAPL",
+            "type": "trailing_stop",
+            "qty": "1",
+            "time_in_force": "day",
+            "trail_percent": "1.5"
+        }
+        "#;
+
+        let order = assert_order_request_round_trips(payload);
+        assert_eq!(order.order_type, OrderType::TrailingStop);
+        assert_eq!(order.trail_percent, Some(1.5));
+        assert_eq!(order.trail_price, None);
+    }
+
+    #[test]
+    fn option_order_with_position_intent_round_trips() {
+        let payload = r#"
+        {
+            "symbol": "AAPL250620C00100000",
+            "qty": "1",
+            "side": "sell",
+            "type": "stop_limit",
+            "stop_price": "1.1",
+            "limit_price": "1",
+            "time_in_force": "day",
+            "position_intent": "sell_to_close"
+        }
+        "#;
+
+        let order = assert_order_request_round_trips(payload);
+        assert_eq!(order.position_intent, Some(PositionIntent::SellToClose));
+    }
+
+    #[test]
+    fn multi_leg_order_round_trips() {
+        let payload = r#"
+        {
+            "order_class": "mleg",
+            "qty": "1",
+            "type": "limit",
+            "limit_price": "0.6",
+            "time_in_force": "day",
+            "legs": [
+                {
+                    "symbol": "AAPL250117P00200000",
+                    "ratio_qty": "1",
+                    "side": "buy",
+                    "position_intent": "buy_to_open"
+                },
+                {
+                    "symbol": "AAPL250117C00250000",
+                    "ratio_qty": "1",
+                    "side": "buy",
+                    "position_intent": "buy_to_open"
+                }
+            ]
+        }
+        "#;
+
+        let order = assert_order_request_round_trips(payload);
+        assert_eq!(order.validate(), Ok(()));
+        assert_eq!(order.symbol, None);
+        assert_eq!(order.side, None);
+        assert_eq!(order.legs.map(|legs| legs[0].ratio_qty), Some(1.0));
+    }
+}
@@ -0,0 +1,218 @@
+use serde::{Deserialize, Serialize};
+use time::serde::rfc3339;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use super::{OrderResult, OrderSide};
+use crate::{comma_separated_from_opt_string, serialize_comma_separated};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "ts")]
+use ts_rs::TS;
+
+/// # OrderQueryStatus
+/// Which orders to return when listing orders. Defaults to `open` on the server.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum OrderQueryStatus {
+    Open,
+    Closed,
+    All,
+}
+
+/// # SortDirection
+/// The chronological order of the returned orders. Defaults to `desc` on the server.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+/// # ListOrdersQuery
+/// the query parameters for listing orders (GET /v2/orders).
+/// Unset fields are left out and fall back to the server defaults.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct ListOrdersQuery {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub status: Option<OrderQueryStatus>,
+    /// the maximum number of orders to return, up to 500.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub limit: Option<u32>,
+    /// only orders submitted after this time.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "rfc3339::option"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::DateTime>")
+    )]
+    pub after: Option<OffsetDateTime>,
+    /// only orders submitted until this time.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "rfc3339::option"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::DateTime>")
+    )]
+    pub until: Option<OffsetDateTime>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub direction: Option<SortDirection>,
+    /// if true, multi-leg orders are rolled up under the `legs` field of the primary order.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub nested: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_comma_separated",
+        deserialize_with = "comma_separated_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub symbols: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub side: Option<OrderSide>,
+}
+
+impl ListOrdersQuery {
+    /// encodes the query as a url query string, without the leading `?`.
+    pub fn to_query_string(&self) -> Result<String, serde_urlencoded::ser::Error> {
+        serde_urlencoded::to_string(self)
+    }
+}
+
+/// The response we get for each order when we cancel all orders.
+/// `body` holds the canceled order, or the error when the order failed to cancel.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(try_from = "RawCancelAllOrdersResponse")]
+pub struct CancelAllOrdersResponse {
+    pub id: Uuid,
+    // NOTE: like closing all positions, the status is the http status code as an int.
+    pub status: u16,
+    pub body: OrderResult,
+}
+
+/// the wire layout of [`CancelAllOrdersResponse`], before `body` is parsed by `status`.
+#[derive(Deserialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+struct RawCancelAllOrdersResponse {
+    id: Uuid,
+    status: u16,
+    body: serde_json::Value,
+}
+
+impl TryFrom<RawCancelAllOrdersResponse> for CancelAllOrdersResponse {
+    type Error = serde_json::Error;
+
+    fn try_from(raw: RawCancelAllOrdersResponse) -> Result<Self, Self::Error> {
+        Ok(CancelAllOrdersResponse {
+            body: OrderResult::from_status(raw.status, raw.body)?,
+            id: raw.id,
+            status: raw.status,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::{CancelAllOrdersResponse, ListOrdersQuery, OrderQueryStatus, SortDirection};
+    use crate::orders::OrderSide;
+
+    #[test]
+    fn empty_query_is_empty() {
+        let got = ListOrdersQuery::default()
+            .to_query_string()
+            .expect("failed to encode query");
+        assert_eq!("", got);
+    }
+
+    #[test]
+    fn list_orders_query_encodes() {
+        let query = ListOrdersQuery {
+            status: Some(OrderQueryStatus::All),
+            limit: Some(100),
+            after: Some(datetime!(2024-07-25 13:30 UTC)),
+            until: None,
+            direction: Some(SortDirection::Asc),
+            nested: Some(true),
+            symbols: Some(vec!["AAPL".to_string(), "BTC/USD".to_string()]),
+            side: Some(OrderSide::Sell),
+        };
+
+        let wanted = "status=all&limit=100&after=2024-07-25T13%3A30%3A00Z&direction=asc\
+                      &nested=true&symbols=AAPL%2CBTC%2FUSD&side=sell";
+        let got = query.to_query_string().expect("failed to encode query");
+        assert_eq!(wanted, got);
+    }
+
+    #[test]
+    fn cancel_all_response_parses() {
+        let input = r#"
+        [
+            {
+                "id": "7b08df51-c1ac-453c-99f9-323a5f075f0d",
+                "status": 200,
+                "body": {
+                    "id": "7b08df51-c1ac-453c-99f9-323a5f075f0d",
+                    "client_order_id": "5680c4bc-9ac1-4a12-a44c-df427ba53032",
+                    "created_at": "2023-12-12T22:31:24.668464435Z",
+                    "updated_at": "2023-12-12T22:31:24.668464435Z",
+                    "submitted_at": "2023-12-12T22:31:24.577215743Z",
+                    "filled_at": null,
+                    "expired_at": null,
+                    "canceled_at": null,
+                    "failed_at": null,
+                    "replaced_at": null,
+                    "replaced_by": null,
+                    "replaces": null,
+                    "asset_id": "b0b6dd9d-8b9b-48a9-ba46-b9d54906e415",
+                    "symbol": "AAPL",
+                    "asset_class": "us_equity",
+                    "notional": null,
+                    "qty": "2",
+                    "filled_qty": "0",
+                    "filled_avg_price": null,
+                    "order_class": "",
+                    "type": "limit",
+                    "side": "buy",
+                    "time_in_force": "gtc",
+                    "limit_price": "150",
+                    "stop_price": null,
+                    "status": "pending_cancel",
+                    "extended_hours": false
+                }
+            }
+        ]
+        "#;
+
+        let parsed = serde_json::from_str::<Vec<CancelAllOrdersResponse>>(input)
+            .expect("failed to parse cancel all response");
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].status, 200);
+        assert!(parsed[0].body.is_ok());
+    }
+}
@@ -0,0 +1,449 @@
+use serde::{Deserialize, Serialize};
+
+use super::{OrderClass, OrderRequest, OrderResponse, OrderType, TimeInForce};
+use crate::{f64_from_opt_string, serialize_opt_qty};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "ts")]
+use ts_rs::TS;
+
+/// # ReplaceOrderRequest
+/// the structure of data to replace (PATCH) an open order.
+/// Every field is optional and only the ones that are set are sent.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct ReplaceOrderRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub qty: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub time_in_force: Option<TimeInForce>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub limit_price: Option<f64>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub stop_price: Option<f64>,
+    /// the new value of the `trail_price` or `trail_percent` of a trailing stop order.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub trail: Option<f64>,
+    /// a unique identifier for the new order.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub client_order_id: Option<String>,
+}
+
+impl ReplaceOrderRequest {
+    /// true when no field is set, sending it would not change the order.
+    pub fn is_empty(&self) -> bool {
+        *self == ReplaceOrderRequest::default()
+    }
+}
+
+/// # ReplaceDiff
+/// the outcome of comparing an existing order with the order we want it to be.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReplaceDiff {
+    /// the existing order already matches.
+    Unchanged,
+    /// the existing order can be patched with this request.
+    Replace(ReplaceOrderRequest),
+    /// the change touches a field the replace endpoint cannot modify,
+    /// so the order needs to be canceled and a new one submitted.
+    CancelAndResubmit(&'static str),
+}
+
+/// parses the string-or-number values Alpaca sends for the trailing fields.
+fn value_to_f64(value: &Option<serde_json::Value>) -> Option<f64> {
+    match value.as_ref()? {
+        serde_json::Value::String(s) => s.parse::<f64>().ok(),
+        serde_json::Value::Number(num) => num.as_f64(),
+        _ => None,
+    }
+}
+
+/// compares two prices or quantities, ignoring float noise from string parsing.
+fn differs(existing: Option<f64>, desired: Option<f64>) -> bool {
+    match (existing, desired) {
+        (Some(a), Some(b)) => (a - b).abs() > 1e-9,
+        (None, None) => false,
+        _ => true,
+    }
+}
+
+/// returns the desired value when it differs from the existing one.
+fn changed(existing: Option<f64>, desired: Option<f64>) -> Option<f64> {
+    if differs(existing, desired) {
+        desired
+    } else {
+        None
+    }
+}
+
+impl OrderResponse {
+    /// the reason `desired` cannot be reached by replacing this order because of its
+    /// position intent or legs, if any. Alpaca replaces the legs of an advanced order
+    /// separately from it, so changed take profits, stop losses or mleg legs need
+    /// a cancel and resubmit.
+    fn leg_change(&self, desired: &OrderRequest) -> Option<&'static str> {
+        if desired.position_intent.is_some() && desired.position_intent != self.position_intent {
+            return Some("position intent cannot be replaced");
+        }
+        if desired.take_profit.is_none() && desired.stop_loss.is_none() && desired.legs.is_none() {
+            return None;
+        }
+        let Some(legs) = &self.legs else {
+            return Some("legs are unknown, fetch the order with nested legs to compare them");
+        };
+
+        if let Some(desired_legs) = &desired.legs {
+            let same = desired_legs.len() == legs.len()
+                && desired_legs.iter().zip(legs).all(|(desired, leg)| {
+                    let ratio_qty = leg.ratio_qty.as_ref().and_then(|qty| qty.parse().ok());
+                    desired.symbol == leg.symbol
+                        && desired.side == leg.order_side
+                        && !differs(Some(desired.ratio_qty), ratio_qty)
+                        && (desired.position_intent.is_none()
+                            || desired.position_intent == leg.position_intent)
+                });
+            return (!same).then_some("legs cannot be replaced");
+        }
+
+        // an oco order is its own take profit, with the stop loss as its leg.
+        let take_profit = legs
+            .iter()
+            .chain((self.order_class == OrderClass::Oco.to_string()).then_some(self))
+            .find(|order| order.order_type == OrderType::Limit)
+            .and_then(|order| order.limit_price);
+        let desired_take_profit = desired.take_profit.as_ref().map(|exit| exit.limit_price);
+        if differs(take_profit, desired_take_profit) {
+            return Some("take profit cannot be replaced with its order");
+        }
+
+        let stop_loss = legs
+            .iter()
+            .find(|leg| matches!(leg.order_type, OrderType::Stop | OrderType::StopLimit));
+        let desired_stop_loss = desired.stop_loss.as_ref();
+        if differs(
+            stop_loss.and_then(|leg| leg.stop_price),
+            desired_stop_loss.map(|exit| exit.stop_price),
+        ) || differs(
+            stop_loss.and_then(|leg| leg.limit_price),
+            desired_stop_loss.and_then(|exit| exit.limit_price),
+        ) {
+            return Some("stop loss cannot be replaced with its order");
+        }
+        None
+    }
+
+    /// computes the smallest [`ReplaceOrderRequest`] that turns this order into `desired`.
+    /// Changes that the replace endpoint cannot make (symbol, side, type, class, notional,
+    /// extended hours, position intent, legs or switching between trail price and percent)
+    /// need a cancel and resubmit. Comparing the legs of an advanced order needs the order
+    /// fetched with `nested`.
+    pub fn diff_replace(&self, desired: &OrderRequest) -> ReplaceDiff {
+        // mleg orders carry their symbols and sides on their legs, compared with the legs.
+        if desired
+            .symbol
+            .as_ref()
+            .is_some_and(|symbol| *symbol != self.symbol)
+        {
+            return ReplaceDiff::CancelAndResubmit("symbol cannot be replaced");
+        }
+        if desired
+            .side
+            .as_ref()
+            .is_some_and(|side| *side != self.order_side)
+        {
+            return ReplaceDiff::CancelAndResubmit("side cannot be replaced");
+        }
+        if self.order_type != desired.order_type {
+            return ReplaceDiff::CancelAndResubmit("order type cannot be replaced");
+        }
+
+        let existing_class = match self.order_class.as_str() {
+            "" => OrderClass::Simple.to_string(),
+            other => other.to_string(),
+        };
+        let desired_class = desired
+            .order_class
+            .as_ref()
+            .unwrap_or(&OrderClass::Simple)
+            .to_string();
+        if existing_class != desired_class {
+            return ReplaceDiff::CancelAndResubmit("order class cannot be replaced");
+        }
+
+        if let Some(reason) = self.leg_change(desired) {
+            return ReplaceDiff::CancelAndResubmit(reason);
+        }
+
+        if self.extended_hours != desired.extended_hours.unwrap_or(false) {
+            return ReplaceDiff::CancelAndResubmit("extended hours cannot be replaced");
+        }
+
+        let existing_notional = self
+            .notional
+            .as_ref()
+            .and_then(|notional| notional.parse::<f64>().ok());
+        if existing_notional.is_some() || desired.notional.is_some() {
+            if differs(existing_notional, desired.notional) {
+                return ReplaceDiff::CancelAndResubmit("notional orders cannot be replaced");
+            }
+        } else if desired.qty.is_none() {
+            return ReplaceDiff::CancelAndResubmit("qty cannot be removed from an order");
+        }
+
+        let existing_trail_price = value_to_f64(&self.trail_price);
+        let existing_trail_percent = value_to_f64(&self.trail_percent);
+        let trail = match (desired.trail_price, desired.trail_percent) {
+            (None, None) => None,
+            (Some(price), None) if existing_trail_percent.is_none() => {
+                changed(existing_trail_price, Some(price))
+            }
+            (None, Some(percent)) if existing_trail_price.is_none() => {
+                changed(existing_trail_percent, Some(percent))
+            }
+            _ => {
+                return ReplaceDiff::CancelAndResubmit(
+                    "trail price and trail percent cannot be swapped",
+                )
+            }
+        };
+
+        let request = ReplaceOrderRequest {
+            qty: changed(self.qty, desired.qty),
+            time_in_force: (self.time_in_force != desired.time_in_force)
+                .then(|| desired.time_in_force.clone()),
+            limit_price: changed(self.limit_price, desired.limit_price),
+            stop_price: changed(self.stop_price, desired.stop_price),
+            trail,
+            client_order_id: desired
+                .client_order_id
+                .as_ref()
+                .filter(|client_order_id| **client_order_id != self.client_order_id)
+                .cloned(),
+        };
+
+        if request.is_empty() {
+            ReplaceDiff::Unchanged
+        } else {
+            ReplaceDiff::Replace(request)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ReplaceDiff, ReplaceOrderRequest};
+    use crate::orders::{
+        OrderRequest, OrderResponse, OrderSide, OrderType, StopLoss, TakeProfit, TimeInForce, Trail,
+    };
+
+    const EXISTING_ORDER: &str = r#"
+        {
+            "id": "7b08df51-c1ac-453c-99f9-323a5f075f0d",
+            "client_order_id": "5680c4bc-9ac1-4a12-a44c-df427ba53032",
+            "created_at": "2023-12-12T22:31:24.668464435Z",
+            "updated_at": "2023-12-12T22:31:24.668464435Z",
+            "submitted_at": "2023-12-12T22:31:24.577215743Z",
+            "filled_at": null,
+            "expired_at": null,
+            "canceled_at": null,
+            "failed_at": null,
+            "replaced_at": null,
+            "replaced_by": null,
+            "replaces": null,
+            "asset_id": "b0b6dd9d-8b9b-48a9-ba46-b9d54906e415",
+            "symbol": "AAPL",
+            "asset_class": "us_equity",
+            "notional": null,
+            "qty": "2",
+            "filled_qty": "0",
+            "filled_avg_price": null,
+            "order_class": "",
+            "type": "limit",
+            "side": "buy",
+            "time_in_force": "gtc",
+            "limit_price": "150",
+            "stop_price": null,
+            "status": "accepted",
+            "extended_hours": false,
+            "trail_percent": null,
+            "trail_price": null,
+            "hwm": null
+        }
+        "#;
+
+    fn existing_order() -> OrderResponse {
+        serde_json::from_str(EXISTING_ORDER).expect("failed to parse order")
+    }
+
+    #[test]
+    fn replace_request_serializes_only_set_fields() {
+        let request = ReplaceOrderRequest {
+            qty: Some(5.0),
+            limit_price: Some(151.5),
+            ..Default::default()
+        };
+        let got = serde_json::to_string(&request).expect("failed to serialize replace");
+        assert_eq!(r#"{"qty":"5","limit_price":"151.5"}"#, got);
+    }
+
+    #[test]
+    fn matching_order_is_unchanged() {
+        let desired = OrderRequest::limit("AAPL", 150.0)
+            .buy()
+            .qty(2)
+            .gtc()
+            .build()
+            .expect("failed to build order");
+
+        assert_eq!(
+            existing_order().diff_replace(&desired),
+            ReplaceDiff::Unchanged
+        );
+    }
+
+    #[test]
+    fn diff_contains_only_changed_fields() {
+        let desired = OrderRequest::limit("AAPL", 149.5)
+            .buy()
+            .qty(2)
+            .day()
+            .build()
+            .expect("failed to build order");
+
+        let wanted = ReplaceOrderRequest {
+            limit_price: Some(149.5),
+            time_in_force: Some(TimeInForce::Day),
+            ..Default::default()
+        };
+        assert_eq!(
+            existing_order().diff_replace(&desired),
+            ReplaceDiff::Replace(wanted)
+        );
+    }
+
+    #[test]
+    fn immutable_changes_need_resubmit() {
+        let desired = OrderRequest::limit("AAPL", 150.0)
+            .sell()
+            .qty(2)
+            .gtc()
+            .build()
+            .expect("failed to build order");
+        assert!(matches!(
+            existing_order().diff_replace(&desired),
+            ReplaceDiff::CancelAndResubmit(_)
+        ));
+
+        let desired = OrderRequest::trailing_stop("AAPL", Trail::Percent(1.0))
+            .buy()
+            .qty(2)
+            .gtc()
+            .build()
+            .expect("failed to build order");
+        assert!(matches!(
+            existing_order().diff_replace(&desired),
+            ReplaceDiff::CancelAndResubmit(_)
+        ));
+    }
+
+    /// the existing order as a bracket with nested take profit and stop loss legs.
+    fn existing_bracket() -> OrderResponse {
+        let mut order = existing_order();
+        order.order_class = "bracket".to_string();
+
+        let mut take_profit = existing_order();
+        take_profit.order_side = OrderSide::Sell;
+        take_profit.limit_price = Some(160.0);
+
+        let mut stop_loss = existing_order();
+        stop_loss.order_side = OrderSide::Sell;
+        stop_loss.order_type = OrderType::Stop;
+        stop_loss.limit_price = None;
+        stop_loss.stop_price = Some(140.0);
+
+        order.legs = Some(vec![take_profit, stop_loss]);
+        order
+    }
+
+    fn desired_bracket(take_profit: f64) -> OrderRequest {
+        OrderRequest::limit("AAPL", 150.0)
+            .buy()
+            .qty(2)
+            .gtc()
+            .bracket(
+                TakeProfit {
+                    limit_price: take_profit,
+                },
+                StopLoss {
+                    stop_price: 140.0,
+                    limit_price: None,
+                },
+            )
+            .build()
+            .expect("failed to build order")
+    }
+
+    #[test]
+    fn bracket_legs_are_compared() {
+        assert_eq!(
+            existing_bracket().diff_replace(&desired_bracket(160.0)),
+            ReplaceDiff::Unchanged
+        );
+        assert!(matches!(
+            existing_bracket().diff_replace(&desired_bracket(165.0)),
+            ReplaceDiff::CancelAndResubmit(_)
+        ));
+
+        // without nested legs the take profit cannot be compared.
+        let mut flat = existing_bracket();
+        flat.legs = None;
+        assert!(matches!(
+            flat.diff_replace(&desired_bracket(160.0)),
+            ReplaceDiff::CancelAndResubmit(_)
+        ));
+    }
+}
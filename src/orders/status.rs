@@ -0,0 +1,328 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use super::OrderStatus;
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "ts")]
+use ts_rs::TS;
+
+/// # OrderEvent
+/// the lifecycle events Alpaca reports for an order on the `trade_updates` stream.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum OrderEvent {
+    PendingNew,
+    New,
+    PartialFill,
+    Fill,
+    DoneForDay,
+    Canceled,
+    Expired,
+    Replaced,
+    Rejected,
+    PendingCancel,
+    PendingReplace,
+    Stopped,
+    Suspended,
+    Calculated,
+    OrderCancelRejected,
+    OrderReplaceRejected,
+    /// an execution of the order was cancelled, its `execution_id` names the busted fill.
+    TradeBust,
+    /// the price or quantity of an execution named by `execution_id` was corrected.
+    TradeCorrect,
+    /// an event this crate does not model yet, e.g. `restated` or `held`,
+    /// kept as sent so it serializes back unchanged.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl Display for OrderEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OrderEvent::PendingNew => write!(f, "pending_new"),
+            OrderEvent::New => write!(f, "new"),
+            OrderEvent::PartialFill => write!(f, "partial_fill"),
+            OrderEvent::Fill => write!(f, "fill"),
+            OrderEvent::DoneForDay => write!(f, "done_for_day"),
+            OrderEvent::Canceled => write!(f, "canceled"),
+            OrderEvent::Expired => write!(f, "expired"),
+            OrderEvent::Replaced => write!(f, "replaced"),
+            OrderEvent::Rejected => write!(f, "rejected"),
+            OrderEvent::PendingCancel => write!(f, "pending_cancel"),
+            OrderEvent::PendingReplace => write!(f, "pending_replace"),
+            OrderEvent::Stopped => write!(f, "stopped"),
+            OrderEvent::Suspended => write!(f, "suspended"),
+            OrderEvent::Calculated => write!(f, "calculated"),
+            OrderEvent::OrderCancelRejected => write!(f, "order_cancel_rejected"),
+            OrderEvent::OrderReplaceRejected => write!(f, "order_replace_rejected"),
+            OrderEvent::TradeBust => write!(f, "trade_bust"),
+            OrderEvent::TradeCorrect => write!(f, "trade_correct"),
+            OrderEvent::Unknown(event) => write!(f, "{event}"),
+        }
+    }
+}
+
+/// # InvalidTransition
+/// an event that cannot happen to an order in its current status.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidTransition {
+    pub from: OrderStatus,
+    pub event: OrderEvent,
+}
+
+impl Display for InvalidTransition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "order in status {} cannot receive event {}",
+            self.from, self.event
+        )
+    }
+}
+
+impl std::error::Error for InvalidTransition {}
+
+impl OrderStatus {
+    /// true when the order will never change again.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            OrderStatus::Filled
+                | OrderStatus::Canceled
+                | OrderStatus::Expired
+                | OrderStatus::Replaced
+                | OrderStatus::Rejected
+        )
+    }
+
+    /// true when the order is still working or can still be acted on.
+    pub fn is_open(&self) -> bool {
+        !self.is_terminal()
+    }
+
+    /// true when a cancel request for the order can be accepted.
+    pub fn can_cancel(&self) -> bool {
+        self.is_open() && !matches!(self, OrderStatus::PendingCancel | OrderStatus::Calculated)
+    }
+
+    /// true when a replace request for the order can be accepted.
+    pub fn can_replace(&self) -> bool {
+        matches!(
+            self,
+            OrderStatus::New
+                | OrderStatus::PartiallyFilled
+                | OrderStatus::Accepted
+                | OrderStatus::AcceptedForBidding
+        )
+    }
+
+    /// applies a lifecycle event to an order that has not filled yet and returns the status
+    /// the order moves to. Use [`OrderStatus::transition_with_filled_qty`] for orders that may
+    /// have partially filled.
+    /// Errors when the event is impossible from the current status,
+    /// e.g. any event after the order reached a terminal status.
+    pub fn transition(&self, event: &OrderEvent) -> Result<OrderStatus, InvalidTransition> {
+        self.transition_with_filled_qty(event, 0.0)
+    }
+
+    /// applies a lifecycle event to an order that filled `filled_qty` so far.
+    ///
+    /// The stream does not say which status an order returns to when a cancel or replace
+    /// is rejected, so the pending order goes back to `partially_filled` when part of it
+    /// filled and to `new` otherwise.
+    pub fn transition_with_filled_qty(
+        &self,
+        event: &OrderEvent,
+        filled_qty: f64,
+    ) -> Result<OrderStatus, InvalidTransition> {
+        let invalid = || InvalidTransition {
+            from: self.clone(),
+            event: event.clone(),
+        };
+
+        // busts and corrections can follow the last fill, the order's snapshot carries the
+        // status they leave it in.
+        if matches!(event, OrderEvent::TradeBust | OrderEvent::TradeCorrect) {
+            return Ok(self.clone());
+        }
+
+        if self.is_terminal() {
+            return Err(invalid());
+        }
+
+        let resumed = if filled_qty > 0.0 {
+            OrderStatus::PartiallyFilled
+        } else {
+            OrderStatus::New
+        };
+
+        let next = match event {
+            OrderEvent::PendingNew => match self {
+                OrderStatus::PendingNew | OrderStatus::Accepted => OrderStatus::PendingNew,
+                _ => return Err(invalid()),
+            },
+            OrderEvent::New => match self {
+                OrderStatus::PendingNew
+                | OrderStatus::Accepted
+                | OrderStatus::AcceptedForBidding
+                | OrderStatus::New
+                | OrderStatus::DoneForDay
+                | OrderStatus::Suspended => OrderStatus::New,
+                _ => return Err(invalid()),
+            },
+            OrderEvent::Rejected => match self {
+                OrderStatus::PendingNew
+                | OrderStatus::Accepted
+                | OrderStatus::AcceptedForBidding
+                | OrderStatus::New => OrderStatus::Rejected,
+                _ => return Err(invalid()),
+            },
+            OrderEvent::PartialFill => OrderStatus::PartiallyFilled,
+            OrderEvent::Fill => OrderStatus::Filled,
+            OrderEvent::DoneForDay => OrderStatus::DoneForDay,
+            OrderEvent::Canceled => OrderStatus::Canceled,
+            OrderEvent::Expired => OrderStatus::Expired,
+            OrderEvent::Replaced => OrderStatus::Replaced,
+            OrderEvent::Stopped => OrderStatus::Stopped,
+            OrderEvent::Suspended => OrderStatus::Suspended,
+            OrderEvent::Calculated => OrderStatus::Calculated,
+            OrderEvent::PendingCancel if self.can_cancel() => OrderStatus::PendingCancel,
+            OrderEvent::PendingReplace if self.can_replace() => OrderStatus::PendingReplace,
+            OrderEvent::PendingCancel | OrderEvent::PendingReplace => return Err(invalid()),
+            OrderEvent::OrderCancelRejected => match self {
+                OrderStatus::PendingCancel => resumed,
+                _ => return Err(invalid()),
+            },
+            OrderEvent::OrderReplaceRejected => match self {
+                OrderStatus::PendingReplace => resumed,
+                _ => return Err(invalid()),
+            },
+            OrderEvent::TradeBust | OrderEvent::TradeCorrect | OrderEvent::Unknown(_) => {
+                self.clone()
+            }
+        };
+
+        Ok(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InvalidTransition, OrderEvent};
+    use crate::orders::OrderStatus;
+
+    #[test]
+    fn status_flags() {
+        assert!(OrderStatus::Filled.is_terminal());
+        assert!(!OrderStatus::Filled.can_cancel());
+        assert!(OrderStatus::PartiallyFilled.is_open());
+        assert!(OrderStatus::PartiallyFilled.can_replace());
+        assert!(!OrderStatus::PendingCancel.can_cancel());
+        assert!(!OrderStatus::PendingReplace.can_replace());
+    }
+
+    #[test]
+    fn happy_path_transitions() {
+        let events = [
+            OrderEvent::PendingNew,
+            OrderEvent::New,
+            OrderEvent::PartialFill,
+            OrderEvent::PartialFill,
+            OrderEvent::Fill,
+        ];
+
+        let status = events
+            .iter()
+            .try_fold(OrderStatus::Accepted, |status, event| {
+                status.transition(event)
+            })
+            .expect("valid lifecycle failed");
+        assert_eq!(status, OrderStatus::Filled);
+    }
+
+    #[test]
+    fn replace_lifecycle() {
+        let status = OrderStatus::New
+            .transition(&OrderEvent::PendingReplace)
+            .and_then(|status| status.transition(&OrderEvent::OrderReplaceRejected))
+            .and_then(|status| status.transition(&OrderEvent::PendingReplace))
+            .and_then(|status| status.transition(&OrderEvent::Replaced))
+            .expect("valid replace failed");
+        assert_eq!(status, OrderStatus::Replaced);
+    }
+
+    #[test]
+    fn rejected_cancel_keeps_partial_fill() {
+        let status = OrderStatus::PartiallyFilled
+            .transition_with_filled_qty(&OrderEvent::PendingCancel, 5.0)
+            .and_then(|status| {
+                status.transition_with_filled_qty(&OrderEvent::OrderCancelRejected, 5.0)
+            })
+            .expect("valid cancel reject failed");
+        assert_eq!(status, OrderStatus::PartiallyFilled);
+
+        let status = OrderStatus::PendingReplace
+            .transition_with_filled_qty(&OrderEvent::OrderReplaceRejected, 5.0)
+            .expect("valid replace reject failed");
+        assert_eq!(status, OrderStatus::PartiallyFilled);
+
+        let status = OrderStatus::PendingCancel
+            .transition(&OrderEvent::OrderCancelRejected)
+            .expect("valid cancel reject failed");
+        assert_eq!(status, OrderStatus::New);
+    }
+
+    #[test]
+    fn unknown_events_parse() {
+        let event: OrderEvent =
+            serde_json::from_str(r#""restated""#).expect("failed to parse unknown event");
+        assert_eq!(event, OrderEvent::Unknown("restated".to_string()));
+        assert_eq!(OrderStatus::New.transition(&event), Ok(OrderStatus::New));
+        assert_eq!(
+            serde_json::to_string(&event).expect("failed to serialize unknown event"),
+            r#""restated""#
+        );
+    }
+
+    #[test]
+    fn busts_follow_fills() {
+        let event: OrderEvent =
+            serde_json::from_str(r#""trade_bust""#).expect("failed to parse trade bust");
+        assert_eq!(event, OrderEvent::TradeBust);
+        assert_eq!(
+            OrderStatus::Filled.transition(&event),
+            Ok(OrderStatus::Filled)
+        );
+    }
+
+    #[test]
+    fn terminal_status_rejects_events() {
+        let got = OrderStatus::Filled.transition(&OrderEvent::Canceled);
+        assert_eq!(
+            got,
+            Err(InvalidTransition {
+                from: OrderStatus::Filled,
+                event: OrderEvent::Canceled,
+            })
+        );
+    }
+
+    #[test]
+    fn impossible_moves_are_rejected() {
+        assert!(OrderStatus::PartiallyFilled
+            .transition(&OrderEvent::Rejected)
+            .is_err());
+        assert!(OrderStatus::New
+            .transition(&OrderEvent::OrderCancelRejected)
+            .is_err());
+        assert!(OrderStatus::PendingCancel
+            .transition(&OrderEvent::PendingCancel)
+            .is_err());
+    }
+}
@@ -0,0 +1,482 @@
+use std::collections::{HashMap, HashSet};
+
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use super::{InvalidTransition, OrderEvent, OrderResponse, TradeUpdate};
+
+/// # Fill
+/// a single execution against an order, taken from a `fill` or `partial_fill` event
+/// and updated by a `trade_correct` event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fill {
+    pub execution_id: Option<Uuid>,
+    pub qty: f64,
+    pub price: f64,
+    pub timestamp: Option<OffsetDateTime>,
+}
+
+/// # TrackedOrder
+/// the latest known state of an order along with the fills we have seen for it.
+#[derive(Debug, Clone)]
+pub struct TrackedOrder {
+    pub order: OrderResponse,
+    pub fills: Vec<Fill>,
+}
+
+impl TrackedOrder {
+    fn new(order: OrderResponse) -> Self {
+        Self {
+            order,
+            fills: vec![],
+        }
+    }
+
+    /// the cumulative filled quantity of the order.
+    /// Falls back to the snapshot's `filled_qty` when it is ahead of the fills we received,
+    /// e.g. when the tracker was started after the order began filling.
+    pub fn filled_qty(&self) -> f64 {
+        let from_fills: f64 = self.fills.iter().map(|fill| fill.qty).sum();
+        let from_snapshot = self.order.filled_qty.parse::<f64>().unwrap_or(0.0);
+        from_fills.max(from_snapshot)
+    }
+
+    /// the volume weighted average price of the order's fills.
+    /// Uses the snapshot's `filled_avg_price` under the same conditions as `filled_qty`.
+    pub fn avg_fill_price(&self) -> Option<f64> {
+        let from_fills: f64 = self.fills.iter().map(|fill| fill.qty).sum();
+        let from_snapshot = self.order.filled_qty.parse::<f64>().unwrap_or(0.0);
+
+        if from_fills > 0.0 && from_fills >= from_snapshot {
+            let notional: f64 = self.fills.iter().map(|fill| fill.qty * fill.price).sum();
+            Some(notional / from_fills)
+        } else {
+            self.order.filled_avg_price
+        }
+    }
+}
+
+/// parses the order ids Alpaca sends as strings in `replaces` and `replaced_by`.
+fn parse_order_id(id: &Option<String>) -> Option<Uuid> {
+    id.as_ref().and_then(|id| Uuid::parse_str(id).ok())
+}
+
+/// # OrderTracker
+/// An in-memory order book built from `OrderResponse` snapshots (e.g. polling GET /v2/orders)
+/// and `trade_updates` stream events. Orders can be looked up by `id` or `client_order_id`
+/// and replace chains are followed through `replaces`/`replaced_by`.
+#[derive(Debug, Default)]
+pub struct OrderTracker {
+    orders: HashMap<Uuid, TrackedOrder>,
+    client_order_ids: HashMap<String, Uuid>,
+    execution_ids: HashSet<Uuid>,
+}
+
+impl OrderTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// stores an order snapshot. Snapshots older than the tracked state (by `updated_at`)
+    /// are ignored so a slow poll cannot roll back a newer stream update.
+    pub fn ingest_order(&mut self, order: OrderResponse) {
+        if let Some(tracked) = self.orders.get(&order.id) {
+            if tracked.order.updated_at > order.updated_at {
+                return;
+            }
+        }
+        self.store(order);
+    }
+
+    /// applies a stream event. Executions are recorded once per `execution_id` whatever the
+    /// order's status, so replaying the same fill is a no-op and a fill arriving after a poll
+    /// stored the filled order is kept. `trade_bust` removes the busted fill and
+    /// `trade_correct` updates it.
+    /// The event's order snapshot replaces the tracked one unless the tracked one is newer
+    /// (by `updated_at`); impossible moves (like any event after an order was filled)
+    /// are rejected with the offending transition.
+    /// Events this crate does not model are ignored.
+    pub fn ingest_update(&mut self, update: TradeUpdate) -> Result<(), InvalidTransition> {
+        if matches!(update.event, OrderEvent::Unknown(_)) {
+            return Ok(());
+        }
+
+        let id = update.order.id;
+        let overwrite = match self.orders.get(&id) {
+            None => Ok(true),
+            Some(tracked) if tracked.order.updated_at > update.order.updated_at => Ok(false),
+            // a poll already stored the status this event reports.
+            Some(tracked) if tracked.order.status == update.order.status => Ok(true),
+            Some(tracked) => tracked
+                .order
+                .status
+                .transition_with_filled_qty(&update.event, tracked.filled_qty())
+                .map(|_| true),
+        };
+        if overwrite == Ok(true) {
+            self.store(update.order);
+        }
+
+        let Some(tracked) = self.orders.get_mut(&id) else {
+            return overwrite.map(|_| ());
+        };
+        match (&update.event, update.execution_id) {
+            (OrderEvent::Fill | OrderEvent::PartialFill, execution_id) => {
+                if let (Some(qty), Some(price)) = (update.qty, update.price) {
+                    let unseen = execution_id.is_none_or(|id| self.execution_ids.insert(id));
+                    if unseen {
+                        tracked.fills.push(Fill {
+                            execution_id,
+                            qty,
+                            price,
+                            timestamp: update.timestamp,
+                        });
+                    }
+                }
+            }
+            (OrderEvent::TradeBust, Some(execution_id)) => {
+                // the id stays known, so a replay of the busted fill is not recorded again.
+                self.execution_ids.insert(execution_id);
+                tracked
+                    .fills
+                    .retain(|fill| fill.execution_id != Some(execution_id));
+            }
+            (OrderEvent::TradeCorrect, Some(execution_id)) => {
+                let corrected = tracked
+                    .fills
+                    .iter_mut()
+                    .find(|fill| fill.execution_id == Some(execution_id));
+                if let Some(fill) = corrected {
+                    fill.qty = update.qty.unwrap_or(fill.qty);
+                    fill.price = update.price.unwrap_or(fill.price);
+                }
+            }
+            _ => {}
+        }
+
+        overwrite.map(|_| ())
+    }
+
+    /// replaces the order snapshot, keeping fills and linking it into its replace chain.
+    fn store(&mut self, order: OrderResponse) {
+        if let Some(replaced_id) = parse_order_id(&order.replaces) {
+            if let Some(replaced) = self.orders.get_mut(&replaced_id) {
+                if replaced.order.replaced_by.is_none() {
+                    replaced.order.replaced_by = Some(order.id.to_string());
+                }
+            }
+        }
+
+        self.client_order_ids
+            .insert(order.client_order_id.clone(), order.id);
+
+        match self.orders.get_mut(&order.id) {
+            Some(tracked) => tracked.order = order,
+            None => {
+                self.orders.insert(order.id, TrackedOrder::new(order));
+            }
+        }
+    }
+
+    pub fn get(&self, id: &Uuid) -> Option<&TrackedOrder> {
+        self.orders.get(id)
+    }
+
+    pub fn get_by_client_order_id(&self, client_order_id: &str) -> Option<&TrackedOrder> {
+        self.client_order_ids
+            .get(client_order_id)
+            .and_then(|id| self.orders.get(id))
+    }
+
+    /// every tracked order that has not reached a terminal status.
+    pub fn open_orders(&self) -> impl Iterator<Item = &TrackedOrder> {
+        self.orders
+            .values()
+            .filter(|tracked| tracked.order.status.is_open())
+    }
+
+    /// the fills received for an order, in the order they arrived.
+    pub fn fills(&self, id: &Uuid) -> &[Fill] {
+        self.orders
+            .get(id)
+            .map(|tracked| tracked.fills.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// the ids of the replace chain `id` belongs to, from the original order to the latest one.
+    /// Orders of the chain that are not tracked end the walk in that direction.
+    pub fn replace_chain(&self, id: &Uuid) -> Vec<Uuid> {
+        let mut chain = vec![*id];
+
+        let mut current = *id;
+        while let Some(previous) = self
+            .orders
+            .get(&current)
+            .and_then(|tracked| parse_order_id(&tracked.order.replaces))
+            .filter(|previous| !chain.contains(previous))
+        {
+            chain.insert(0, previous);
+            current = previous;
+        }
+
+        let mut current = *id;
+        while let Some(next) = self
+            .orders
+            .get(&current)
+            .and_then(|tracked| parse_order_id(&tracked.order.replaced_by))
+            .filter(|next| !chain.contains(next))
+        {
+            chain.push(next);
+            current = next;
+        }
+
+        chain
+    }
+
+    /// the newest tracked order of the replace chain `id` belongs to.
+    pub fn latest(&self, id: &Uuid) -> Option<&TrackedOrder> {
+        self.replace_chain(id)
+            .iter()
+            .rev()
+            .find_map(|id| self.orders.get(id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+    use uuid::Uuid;
+
+    use super::OrderTracker;
+    use crate::orders::{OrderEvent, OrderResponse, OrderStatus, TradeUpdate};
+
+    const ORIGINAL_ID: &str = "7b08df51-c1ac-453c-99f9-323a5f075f0d";
+    const REPLACEMENT_ID: &str = "2d1b9a6e-5c43-4b9e-9d4b-2f0e4a3c8a11";
+
+    fn order(id: &str, client_order_id: &str, status: &str, filled_qty: &str) -> OrderResponse {
+        let input = format!(
+            r#"
+            {{
+                "id": "{id}",
+                "client_order_id": "{client_order_id}",
+                "created_at": "2023-12-12T22:31:24.668464435Z",
+                "updated_at": "2023-12-12T22:31:24.668464435Z",
+                "submitted_at": "2023-12-12T22:31:24.577215743Z",
+                "filled_at": null,
+                "expired_at": null,
+                "canceled_at": null,
+                "failed_at": null,
+                "replaced_at": null,
+                "replaced_by": null,
+                "replaces": null,
+                "asset_id": "b0b6dd9d-8b9b-48a9-ba46-b9d54906e415",
+                "symbol": "AAPL",
+                "asset_class": "us_equity",
+                "notional": null,
+                "qty": "10",
+                "filled_qty": "{filled_qty}",
+                "filled_avg_price": null,
+                "order_class": "",
+                "type": "limit",
+                "side": "buy",
+                "time_in_force": "day",
+                "limit_price": "150",
+                "stop_price": null,
+                "status": "{status}",
+                "extended_hours": false
+            }}
+            "#
+        );
+        serde_json::from_str(&input).expect("failed to parse order")
+    }
+
+    fn fill(order: OrderResponse, event: OrderEvent, qty: f64, price: f64) -> TradeUpdate {
+        TradeUpdate {
+            event,
+            execution_id: Some(Uuid::new_v4()),
+            order,
+            timestamp: None,
+            price: Some(price),
+            qty: Some(qty),
+            position_qty: None,
+            #[cfg(feature = "extra_fields")]
+            extra: Default::default(),
+        }
+    }
+
+    #[test]
+    fn tracks_fills_and_average_price() {
+        let mut tracker = OrderTracker::new();
+        tracker.ingest_order(order(ORIGINAL_ID, "client-1", "new", "0"));
+        assert_eq!(tracker.open_orders().count(), 1);
+
+        let partial = fill(
+            order(ORIGINAL_ID, "client-1", "partially_filled", "4"),
+            OrderEvent::PartialFill,
+            4.0,
+            149.0,
+        );
+        tracker
+            .ingest_update(partial.clone())
+            .expect("partial fill rejected");
+        // the same execution replayed is ignored.
+        tracker.ingest_update(partial).expect("replay rejected");
+
+        tracker
+            .ingest_update(fill(
+                order(ORIGINAL_ID, "client-1", "filled", "10"),
+                OrderEvent::Fill,
+                6.0,
+                150.0,
+            ))
+            .expect("fill rejected");
+
+        let tracked = tracker
+            .get_by_client_order_id("client-1")
+            .expect("order not tracked");
+        assert_eq!(tracked.fills.len(), 2);
+        assert_eq!(tracked.filled_qty(), 10.0);
+        assert_eq!(tracked.avg_fill_price(), Some(149.6));
+        assert_eq!(tracker.open_orders().count(), 0);
+    }
+
+    #[test]
+    fn keeps_fills_behind_a_poll() {
+        let mut tracker = OrderTracker::new();
+        let mut filled = order(ORIGINAL_ID, "client-1", "filled", "10");
+        filled.updated_at = datetime!(2023-12-12 22:31:30 UTC);
+        tracker.ingest_order(filled.clone());
+
+        // the partial fill was sent before the poll saw the order filled.
+        tracker
+            .ingest_update(fill(
+                order(ORIGINAL_ID, "client-1", "partially_filled", "4"),
+                OrderEvent::PartialFill,
+                4.0,
+                149.0,
+            ))
+            .expect("late partial fill rejected");
+        tracker
+            .ingest_update(fill(filled, OrderEvent::Fill, 6.0, 150.0))
+            .expect("fill after poll rejected");
+
+        let id = Uuid::parse_str(ORIGINAL_ID).expect("bad uuid");
+        let tracked = tracker.get(&id).expect("order not tracked");
+        assert_eq!(tracked.order.status, OrderStatus::Filled);
+        assert_eq!(tracked.fills.len(), 2);
+        assert_eq!(tracked.avg_fill_price(), Some(149.6));
+    }
+
+    #[test]
+    fn applies_busts_and_corrections() {
+        let mut tracker = OrderTracker::new();
+        tracker.ingest_order(order(ORIGINAL_ID, "client-1", "new", "0"));
+
+        let partial = fill(
+            order(ORIGINAL_ID, "client-1", "partially_filled", "4"),
+            OrderEvent::PartialFill,
+            4.0,
+            149.0,
+        );
+        let last = fill(
+            order(ORIGINAL_ID, "client-1", "filled", "10"),
+            OrderEvent::Fill,
+            6.0,
+            150.0,
+        );
+        tracker
+            .ingest_update(partial.clone())
+            .expect("partial fill rejected");
+        tracker.ingest_update(last.clone()).expect("fill rejected");
+
+        let mut correct = last.clone();
+        correct.event = OrderEvent::TradeCorrect;
+        correct.price = Some(151.0);
+        correct.order.updated_at = datetime!(2023-12-12 22:31:30 UTC);
+        tracker.ingest_update(correct).expect("correction rejected");
+
+        let mut bust = partial.clone();
+        bust.event = OrderEvent::TradeBust;
+        bust.order = order(ORIGINAL_ID, "client-1", "partially_filled", "6");
+        bust.order.updated_at = datetime!(2023-12-12 22:31:40 UTC);
+        tracker.ingest_update(bust).expect("bust rejected");
+        // a replay of the busted fill is not recorded again.
+        tracker.ingest_update(partial).expect("replay rejected");
+
+        let id = Uuid::parse_str(ORIGINAL_ID).expect("bad uuid");
+        let tracked = tracker.get(&id).expect("order not tracked");
+        assert_eq!(tracked.order.status, OrderStatus::PartiallyFilled);
+        assert_eq!(tracked.fills.len(), 1);
+        assert_eq!(tracked.filled_qty(), 6.0);
+        assert_eq!(tracked.avg_fill_price(), Some(151.0));
+    }
+
+    #[test]
+    fn rejects_impossible_transitions() {
+        let mut tracker = OrderTracker::new();
+        tracker.ingest_order(order(ORIGINAL_ID, "client-1", "filled", "10"));
+
+        let late_cancel = TradeUpdate {
+            event: OrderEvent::Canceled,
+            execution_id: None,
+            order: order(ORIGINAL_ID, "client-1", "canceled", "10"),
+            timestamp: None,
+            price: None,
+            qty: None,
+            position_qty: None,
+            #[cfg(feature = "extra_fields")]
+            extra: Default::default(),
+        };
+        assert!(tracker.ingest_update(late_cancel).is_err());
+
+        let id = Uuid::parse_str(ORIGINAL_ID).expect("bad uuid");
+        assert_eq!(
+            tracker.get(&id).map(|tracked| &tracked.order.status),
+            Some(&OrderStatus::Filled)
+        );
+    }
+
+    #[test]
+    fn ignores_unknown_events() {
+        let mut tracker = OrderTracker::new();
+        tracker.ingest_order(order(ORIGINAL_ID, "client-1", "filled", "10"));
+
+        let restated = TradeUpdate {
+            event: OrderEvent::Unknown("restated".to_string()),
+            execution_id: None,
+            order: order(ORIGINAL_ID, "client-1", "partially_filled", "4"),
+            timestamp: None,
+            price: None,
+            qty: None,
+            position_qty: None,
+            #[cfg(feature = "extra_fields")]
+            extra: Default::default(),
+        };
+        assert_eq!(tracker.ingest_update(restated), Ok(()));
+
+        let id = Uuid::parse_str(ORIGINAL_ID).expect("bad uuid");
+        assert_eq!(
+            tracker.get(&id).map(|tracked| &tracked.order.status),
+            Some(&OrderStatus::Filled)
+        );
+    }
+
+    #[test]
+    fn follows_replace_chains() {
+        let mut tracker = OrderTracker::new();
+        tracker.ingest_order(order(ORIGINAL_ID, "client-1", "replaced", "0"));
+
+        let mut replacement = order(REPLACEMENT_ID, "client-2", "new", "0");
+        replacement.replaces = Some(ORIGINAL_ID.to_string());
+        tracker.ingest_order(replacement);
+
+        let original = Uuid::parse_str(ORIGINAL_ID).expect("bad uuid");
+        let latest = Uuid::parse_str(REPLACEMENT_ID).expect("bad uuid");
+        assert_eq!(tracker.replace_chain(&original), vec![original, latest]);
+        assert_eq!(tracker.replace_chain(&latest), vec![original, latest]);
+        assert_eq!(
+            tracker.latest(&original).map(|tracked| tracked.order.id),
+            Some(latest)
+        );
+    }
+}
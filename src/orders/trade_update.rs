@@ -0,0 +1,140 @@
+use serde::{Deserialize, Serialize};
+use time::serde::rfc3339;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use super::{OrderEvent, OrderResponse};
+use crate::{f64_from_opt_string, serialize_opt_qty};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "ts")]
+use ts_rs::TS;
+
+/// # TradeUpdate
+/// an order update received on the `trade_updates` stream.
+/// `price`, `qty` and `execution_id` are only present for `fill` and `partial_fill` events,
+/// and for `trade_bust` and `trade_correct` events naming the execution they change.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+pub struct TradeUpdate {
+    pub event: OrderEvent,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub execution_id: Option<Uuid>,
+
+    pub order: OrderResponse,
+
+    #[serde(default, with = "rfc3339::option")]
+    #[cfg_attr(feature = "ts", ts(type = "string | null"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::DateTime>")
+    )]
+    pub timestamp: Option<OffsetDateTime>,
+
+    /// the price of this fill.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub price: Option<f64>,
+
+    /// the quantity of this fill.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub qty: Option<f64>,
+
+    /// the size of the position after this fill.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub position_qty: Option<f64>,
+    /// fields Alpaca sent that this type does not know yet.
+    #[cfg(feature = "extra_fields")]
+    #[cfg_attr(not(feature = "deny_unknown_fields"), serde(flatten))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(skip))]
+    #[cfg_attr(feature = "ts", ts(skip))]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TradeUpdate;
+    use crate::orders::{OrderEvent, OrderStatus};
+
+    #[test]
+    fn fill_update_parses() {
+        let input = r#"
+        {
+            "event": "fill",
+            "execution_id": "0c2c5a4e-1c6f-4a9b-8e3a-8e8c8c5c6e1f",
+            "price": "179.08",
+            "qty": "1",
+            "position_qty": "1",
+            "timestamp": "2023-12-12T22:31:25.123456789Z",
+            "order": {
+                "id": "7b08df51-c1ac-453c-99f9-323a5f075f0d",
+                "client_order_id": "5680c4bc-9ac1-4a12-a44c-df427ba53032",
+                "created_at": "2023-12-12T22:31:24.668464435Z",
+                "updated_at": "2023-12-12T22:31:25.123456789Z",
+                "submitted_at": "2023-12-12T22:31:24.577215743Z",
+                "filled_at": "2023-12-12T22:31:25.123456789Z",
+                "expired_at": null,
+                "canceled_at": null,
+                "failed_at": null,
+                "replaced_at": null,
+                "replaced_by": null,
+                "replaces": null,
+                "asset_id": "b0b6dd9d-8b9b-48a9-ba46-b9d54906e415",
+                "symbol": "AAPL",
+                "asset_class": "us_equity",
+                "notional": null,
+                "qty": "1",
+                "filled_qty": "1",
+                "filled_avg_price": "179.08",
+                "order_class": "",
+                "type": "market",
+                "side": "buy",
+                "time_in_force": "day",
+                "limit_price": null,
+                "stop_price": null,
+                "status": "filled",
+                "extended_hours": false
+            }
+        }
+        "#;
+
+        let update = serde_json::from_str::<TradeUpdate>(input).expect("failed to parse update");
+        assert_eq!(update.event, OrderEvent::Fill);
+        assert_eq!(update.price, Some(179.08));
+        assert_eq!(update.order.status, OrderStatus::Filled);
+    }
+}
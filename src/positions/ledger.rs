@@ -0,0 +1,360 @@
+use std::fmt::Display;
+
+use super::{OpenPosition, PositionSide};
+use crate::orders::{OrderEvent, OrderSide, TradeUpdate};
+
+/// quantities smaller than this are treated as a flat position.
+const FLAT_EPSILON: f64 = 1e-9;
+
+/// # PositionFill
+/// a single execution applied to a [`PositionLedger`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PositionFill {
+    pub side: OrderSide,
+    pub qty: f64,
+    pub price: f64,
+}
+
+impl PositionFill {
+    /// takes the fill out of a `fill` or `partial_fill` trade update.
+    /// Returns `None` for every other event or when the price or quantity is missing.
+    pub fn from_trade_update(update: &TradeUpdate) -> Option<PositionFill> {
+        if !matches!(update.event, OrderEvent::Fill | OrderEvent::PartialFill) {
+            return None;
+        }
+
+        Some(PositionFill {
+            side: update.order.order_side.clone(),
+            qty: update.qty?,
+            price: update.price?,
+        })
+    }
+}
+
+/// # InvalidFill
+/// a fill the ledger refuses because its `qty` is not a positive number or its `price`
+/// is not finite. The ledger is left unchanged.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidFill(pub PositionFill);
+
+impl Display for InvalidFill {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "fill of {} at {} is invalid, qty must be greater than zero and price finite",
+            self.0.qty, self.0.price
+        )
+    }
+}
+
+impl std::error::Error for InvalidFill {}
+
+/// # PositionLedger
+/// Rebuilds a position from our own fills so it can be reconciled against [`OpenPosition`].
+/// Quantities and cost basis are signed like Alpaca's: negative for short positions.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PositionLedger {
+    pub symbol: String,
+    qty: f64,
+    avg_entry_price: f64,
+    realized_pl: f64,
+}
+
+impl PositionLedger {
+    pub fn new(symbol: impl Into<String>) -> Self {
+        Self {
+            symbol: symbol.into(),
+            ..Default::default()
+        }
+    }
+
+    /// applies a fill. Fills against the position realize P&L at the average entry price,
+    /// and a fill larger than the position flips it to the other side at the fill price.
+    pub fn apply(&mut self, fill: &PositionFill) -> Result<(), InvalidFill> {
+        if !(fill.qty.is_finite() && fill.qty > 0.0 && fill.price.is_finite()) {
+            return Err(InvalidFill(fill.clone()));
+        }
+
+        let direction = match fill.side {
+            OrderSide::Buy => 1.0,
+            OrderSide::Sell => -1.0,
+        };
+
+        if self.is_flat() || self.qty.signum() == direction {
+            let held = self.qty.abs();
+            self.avg_entry_price =
+                (held * self.avg_entry_price + fill.qty * fill.price) / (held + fill.qty);
+            self.qty += direction * fill.qty;
+            return Ok(());
+        }
+
+        let closing = fill.qty.min(self.qty.abs());
+        self.realized_pl += closing * (fill.price - self.avg_entry_price) * self.qty.signum();
+        self.qty += direction * closing;
+
+        let remaining = fill.qty - closing;
+        if remaining > FLAT_EPSILON {
+            self.qty = direction * remaining;
+            self.avg_entry_price = fill.price;
+        } else if self.is_flat() {
+            self.qty = 0.0;
+            self.avg_entry_price = 0.0;
+        }
+        Ok(())
+    }
+
+    /// true when there is no open position.
+    pub fn is_flat(&self) -> bool {
+        self.qty.abs() < FLAT_EPSILON
+    }
+
+    /// the signed quantity held.
+    pub fn qty(&self) -> f64 {
+        self.qty
+    }
+
+    pub fn side(&self) -> Option<PositionSide> {
+        if self.is_flat() {
+            None
+        } else if self.qty > 0.0 {
+            Some(PositionSide::Long)
+        } else {
+            Some(PositionSide::Short)
+        }
+    }
+
+    pub fn avg_entry_price(&self) -> Option<f64> {
+        (!self.is_flat()).then_some(self.avg_entry_price)
+    }
+
+    /// the signed cost of the open position.
+    pub fn cost_basis(&self) -> f64 {
+        self.qty * self.avg_entry_price
+    }
+
+    /// P&L locked in by closing fills.
+    pub fn realized_pl(&self) -> f64 {
+        self.realized_pl
+    }
+
+    /// compares the ledger with the server's view of the position.
+    /// Returns every field outside of `tolerance`, an empty list means they agree.
+    pub fn diff(&self, position: &OpenPosition, tolerance: &Tolerance) -> Vec<PositionMismatch> {
+        let mut mismatches = vec![];
+
+        if self.symbol != position.symbol {
+            mismatches.push(PositionMismatch::Symbol {
+                ledger: self.symbol.clone(),
+                server: position.symbol.clone(),
+            });
+        }
+
+        if self.side().as_ref() != Some(&position.side) {
+            mismatches.push(PositionMismatch::Side {
+                ledger: self.side(),
+                server: position.side.clone(),
+            });
+        }
+
+        if (self.qty - position.qty).abs() > tolerance.qty {
+            mismatches.push(PositionMismatch::Qty {
+                ledger: self.qty,
+                server: position.qty,
+            });
+        }
+
+        let avg_entry_differs = match (self.avg_entry_price(), position.avg_entry_price) {
+            (Some(ledger), Some(server)) => (ledger - server).abs() > tolerance.price,
+            (None, None) => false,
+            _ => true,
+        };
+        if avg_entry_differs {
+            mismatches.push(PositionMismatch::AvgEntryPrice {
+                ledger: self.avg_entry_price(),
+                server: position.avg_entry_price,
+            });
+        }
+
+        if (self.cost_basis() - position.cost_basis).abs() > tolerance.value {
+            mismatches.push(PositionMismatch::CostBasis {
+                ledger: self.cost_basis(),
+                server: position.cost_basis,
+            });
+        }
+
+        mismatches
+    }
+}
+
+/// # Tolerance
+/// the largest absolute difference accepted for each kind of field when reconciling.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tolerance {
+    pub qty: f64,
+    pub price: f64,
+    pub value: f64,
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Self {
+            qty: 1e-9,
+            price: 1e-4,
+            value: 0.01,
+        }
+    }
+}
+
+/// # PositionMismatch
+/// a field where the ledger and the server disagree.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PositionMismatch {
+    Symbol {
+        ledger: String,
+        server: String,
+    },
+    Side {
+        ledger: Option<PositionSide>,
+        server: PositionSide,
+    },
+    Qty {
+        ledger: f64,
+        server: f64,
+    },
+    AvgEntryPrice {
+        ledger: Option<f64>,
+        server: Option<f64>,
+    },
+    CostBasis {
+        ledger: f64,
+        server: f64,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InvalidFill, PositionFill, PositionLedger, PositionMismatch, Tolerance};
+    use crate::orders::OrderSide;
+    use crate::positions::{OpenPosition, PositionSide};
+
+    fn fill(side: OrderSide, qty: f64, price: f64) -> PositionFill {
+        PositionFill { side, qty, price }
+    }
+
+    #[test]
+    fn averages_entries_and_realizes_on_close() {
+        let mut ledger = PositionLedger::new("AAPL");
+        ledger
+            .apply(&fill(OrderSide::Buy, 10.0, 100.0))
+            .expect("fill rejected");
+        ledger
+            .apply(&fill(OrderSide::Buy, 10.0, 110.0))
+            .expect("fill rejected");
+        assert_eq!(ledger.qty(), 20.0);
+        assert_eq!(ledger.avg_entry_price(), Some(105.0));
+        assert_eq!(ledger.cost_basis(), 2100.0);
+
+        ledger
+            .apply(&fill(OrderSide::Sell, 5.0, 115.0))
+            .expect("fill rejected");
+        assert_eq!(ledger.qty(), 15.0);
+        assert_eq!(ledger.avg_entry_price(), Some(105.0));
+        assert_eq!(ledger.realized_pl(), 50.0);
+
+        ledger
+            .apply(&fill(OrderSide::Sell, 15.0, 100.0))
+            .expect("fill rejected");
+        assert!(ledger.is_flat());
+        assert_eq!(ledger.side(), None);
+        assert_eq!(ledger.realized_pl(), -25.0);
+    }
+
+    #[test]
+    fn flips_from_long_to_short() {
+        let mut ledger = PositionLedger::new("PENN");
+        ledger
+            .apply(&fill(OrderSide::Buy, 50.0, 18.0))
+            .expect("fill rejected");
+        ledger
+            .apply(&fill(OrderSide::Sell, 150.0, 18.5))
+            .expect("fill rejected");
+
+        assert_eq!(ledger.side(), Some(PositionSide::Short));
+        assert_eq!(ledger.qty(), -100.0);
+        assert_eq!(ledger.avg_entry_price(), Some(18.5));
+        assert_eq!(ledger.cost_basis(), -1850.0);
+        assert_eq!(ledger.realized_pl(), 25.0);
+
+        // covering a short below entry is a gain.
+        ledger
+            .apply(&fill(OrderSide::Buy, 100.0, 18.0))
+            .expect("fill rejected");
+        assert!(ledger.is_flat());
+        assert_eq!(ledger.realized_pl(), 75.0);
+    }
+
+    #[test]
+    fn rejects_invalid_fills() {
+        let mut ledger = PositionLedger::new("AAPL");
+        for invalid in [
+            fill(OrderSide::Buy, 0.0, 100.0),
+            fill(OrderSide::Buy, -1.0, 100.0),
+            fill(OrderSide::Buy, f64::NAN, 100.0),
+            fill(OrderSide::Buy, 1.0, f64::INFINITY),
+        ] {
+            assert!(ledger.apply(&invalid).is_err(), "{invalid:?} was applied");
+        }
+        assert!(ledger.is_flat());
+
+        let empty = fill(OrderSide::Sell, 0.0, 100.0);
+        assert_eq!(ledger.apply(&empty), Err(InvalidFill(empty.clone())));
+
+        ledger
+            .apply(&fill(OrderSide::Buy, 10.0, 100.0))
+            .expect("fill rejected");
+        assert_eq!(ledger.avg_entry_price(), Some(100.0));
+    }
+
+    #[test]
+    fn diffs_against_server_position() {
+        let input = r#"
+        {
+            "asset_id":"02ee28f8-d7a5-41ef-b212-a66d8dd85c4d",
+            "symbol":"PENN",
+            "exchange":"NASDAQ",
+            "asset_class":"us_equity",
+            "avg_entry_price":"18.36",
+            "qty":"-100",
+            "qty_available":"-100",
+            "side":"short",
+            "market_value":"-1821.0",
+            "cost_basis":"-1836.0",
+            "unrealized_pl":"1.5",
+            "unrealized_plpc":"0.0081699346405229",
+            "unrealized_intraday_pl":"1.5",
+            "unrealized_intraday_plpc":"0.01",
+            "current_price":"18.21",
+            "lastday_price":"18.57",
+            "change_today":"-0.0193861066235864",
+            "asset_marginable":true
+        }
+        "#;
+        let position = serde_json::from_str::<OpenPosition>(input).expect("failed to parse");
+
+        let mut ledger = PositionLedger::new("PENN");
+        ledger
+            .apply(&fill(OrderSide::Sell, 100.0, 18.36))
+            .expect("fill rejected");
+        assert!(ledger.diff(&position, &Tolerance::default()).is_empty());
+
+        ledger
+            .apply(&fill(OrderSide::Sell, 1.0, 18.36))
+            .expect("fill rejected");
+        let mismatches = ledger.diff(&position, &Tolerance::default());
+        assert!(mismatches.contains(&PositionMismatch::Qty {
+            ledger: -101.0,
+            server: -100.0
+        }));
+        assert_eq!(mismatches.len(), 2);
+    }
+}
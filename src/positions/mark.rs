@@ -0,0 +1,130 @@
+use super::OpenPosition;
+use crate::market_data::crypto::CryptoBar;
+use crate::market_data::stock::{Bar, Trade};
+
+/// # MarkPrice
+/// anything that can provide a fresh price to mark a position to.
+pub trait MarkPrice {
+    fn mark_price(&self) -> f64;
+}
+
+impl MarkPrice for f64 {
+    fn mark_price(&self) -> f64 {
+        *self
+    }
+}
+
+impl MarkPrice for Trade {
+    fn mark_price(&self) -> f64 {
+        self.price
+    }
+}
+
+/// bars are marked at their close.
+impl MarkPrice for CryptoBar {
+    fn mark_price(&self) -> f64 {
+        self.close
+    }
+}
+
+impl MarkPrice for Bar {
+    fn mark_price(&self) -> f64 {
+        self.close
+    }
+}
+
+/// divides, returning zero instead of infinity or NaN for an empty denominator.
+fn ratio(numerator: f64, denominator: f64) -> f64 {
+    if denominator == 0.0 {
+        0.0
+    } else {
+        numerator / denominator
+    }
+}
+
+impl OpenPosition {
+    /// returns a copy of the position marked to `price`, with every price derived field
+    /// recomputed the way Alpaca does. Because `qty`, `market_value` and `cost_basis` are
+    /// negative for shorts, the same formulas hold for both sides.
+    pub fn mark_to_market(&self, price: impl MarkPrice) -> OpenPosition {
+        let current_price = price.mark_price();
+        let market_value = self.qty * current_price;
+        let unrealized_pl = market_value - self.cost_basis;
+        let lastday_value = self.qty * self.lastday_price;
+        let unrealized_intraday_pl = market_value - lastday_value;
+
+        OpenPosition {
+            current_price,
+            market_value,
+            unrealized_pl,
+            unrealized_plpc: ratio(unrealized_pl, self.cost_basis.abs()),
+            unrealized_intraday_pl,
+            unrealized_intraday_plpc: ratio(unrealized_intraday_pl, lastday_value.abs()),
+            change_today: ratio(current_price - self.lastday_price, self.lastday_price),
+            ..self.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::market_data::stock::Trade;
+    use crate::positions::OpenPosition;
+
+    fn position(side: &str, qty: &str, cost_basis: &str) -> OpenPosition {
+        let input = format!(
+            r#"
+            {{
+                "asset_id": "3fa85f64-5717-4562-b3fc-2c963f66afa6",
+                "symbol": "AAPL",
+                "exchange": "NASDAQ",
+                "asset_class": "us_equity",
+                "avg_entry_price": "100",
+                "qty": "{qty}",
+                "qty_available": "{qty}",
+                "side": "{side}",
+                "market_value": "0",
+                "cost_basis": "{cost_basis}",
+                "unrealized_pl": "0",
+                "unrealized_plpc": "0",
+                "unrealized_intraday_pl": "0",
+                "unrealized_intraday_plpc": "0",
+                "current_price": "100",
+                "lastday_price": "100",
+                "change_today": "0",
+                "asset_marginable": true
+            }}
+            "#
+        );
+        serde_json::from_str(&input).expect("failed to parse position")
+    }
+
+    #[test]
+    fn marks_long_position() {
+        let trade = Trade {
+            price: 110.0,
+            ..Default::default()
+        };
+        let marked = position("long", "10", "1000").mark_to_market(trade);
+
+        assert_eq!(marked.current_price, 110.0);
+        assert_eq!(marked.market_value, 1100.0);
+        assert_eq!(marked.unrealized_pl, 100.0);
+        assert_eq!(marked.unrealized_plpc, 0.1);
+        assert_eq!(marked.unrealized_intraday_pl, 100.0);
+        assert_eq!(marked.unrealized_intraday_plpc, 0.1);
+        assert_eq!(marked.change_today, 0.1);
+    }
+
+    #[test]
+    fn marks_short_position() {
+        let marked = position("short", "-10", "-1000").mark_to_market(90.0);
+
+        assert_eq!(marked.market_value, -900.0);
+        assert_eq!(marked.unrealized_pl, 100.0);
+        assert_eq!(marked.unrealized_plpc, 0.1);
+        assert_eq!(marked.unrealized_intraday_pl, 100.0);
+        assert_eq!(marked.unrealized_intraday_plpc, 0.1);
+        assert_eq!(marked.change_today, -0.1);
+    }
+}
@@ -0,0 +1,299 @@
+mod ledger;
+mod mark;
+mod requests;
+
+pub use ledger::{InvalidFill, PositionFill, PositionLedger, PositionMismatch, Tolerance};
+pub use mark::MarkPrice;
+pub use requests::{
+    CloseAllPositionsRequest, ClosePositionError, ClosePositionRequest, ExerciseOptionRequest,
+};
+
+use crate::errors::AlpacaError;
+use crate::orders::{AssetClass, OrderResponse, OrderResult};
+use crate::{
+    empty_field_is_zero, f64_from_opt_string, serialize_opt_qty, serialize_qty, string_to_f64,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "ts")]
+use ts_rs::TS;
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum PositionSide {
+    Long,
+    Short,
+}
+
+// TODO: Finish this
+/// OpenPosition
+/// Describes the data layout of an open position on alpaca.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+pub struct OpenPosition {
+    pub asset_id: Uuid,
+    pub symbol: String,
+    pub exchange: String,
+    pub asset_class: AssetClass,
+    #[serde(
+        serialize_with = "serialize_opt_qty",
+        deserialize_with = "f64_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(type = "string | null"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub avg_entry_price: Option<f64>,
+    #[serde(serialize_with = "serialize_qty", deserialize_with = "string_to_f64")]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::schema::NumberString"))]
+    pub qty: f64,
+    #[serde(serialize_with = "serialize_qty", deserialize_with = "string_to_f64")]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::schema::NumberString"))]
+    pub qty_available: f64,
+    pub side: PositionSide,
+    #[serde(serialize_with = "serialize_qty", deserialize_with = "string_to_f64")]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::schema::NumberString"))]
+    pub market_value: f64,
+    #[serde(serialize_with = "serialize_qty", deserialize_with = "string_to_f64")]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::schema::NumberString"))]
+    pub cost_basis: f64,
+    #[serde(serialize_with = "serialize_qty", deserialize_with = "string_to_f64")]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::schema::NumberString"))]
+    pub unrealized_pl: f64,
+    #[serde(serialize_with = "serialize_qty", deserialize_with = "string_to_f64")]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::schema::NumberString"))]
+    pub unrealized_plpc: f64,
+    #[serde(serialize_with = "serialize_qty", deserialize_with = "string_to_f64")]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::schema::NumberString"))]
+    pub unrealized_intraday_pl: f64,
+    /// sent as an empty string before the first trade of the day, which parses as zero.
+    #[serde(
+        serialize_with = "serialize_qty",
+        deserialize_with = "empty_field_is_zero"
+    )]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    pub unrealized_intraday_plpc: f64,
+    #[serde(serialize_with = "serialize_qty", deserialize_with = "string_to_f64")]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::schema::NumberString"))]
+    pub current_price: f64,
+    #[serde(serialize_with = "serialize_qty", deserialize_with = "string_to_f64")]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::schema::NumberString"))]
+    pub lastday_price: f64,
+    #[serde(serialize_with = "serialize_qty", deserialize_with = "string_to_f64")]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::schema::NumberString"))]
+    pub change_today: f64,
+    pub asset_marginable: bool,
+    /// fields Alpaca sent that this type does not know yet.
+    #[cfg(feature = "extra_fields")]
+    #[cfg_attr(not(feature = "deny_unknown_fields"), serde(flatten))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(skip))]
+    #[cfg_attr(feature = "ts", ts(skip))]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
+}
+
+/// The response we get when we close a position.
+/// `body` holds the closing order, or the error when the position failed to close.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(try_from = "RawCloseAllPositionsResponse")]
+pub struct CloseAllPositionsResponse {
+    pub symbol: String,
+    // NOTE: at this time (Oct. 30, 2024), alpacas documentation shows the incorrect type for
+    // status. The correct type is int (from testing this response).
+    pub status: u16,
+    pub body: OrderResult,
+}
+
+/// the wire layout of [`CloseAllPositionsResponse`], before `body` is parsed by `status`.
+#[derive(Deserialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+struct RawCloseAllPositionsResponse {
+    symbol: String,
+    status: u16,
+    body: serde_json::Value,
+}
+
+impl TryFrom<RawCloseAllPositionsResponse> for CloseAllPositionsResponse {
+    type Error = serde_json::Error;
+
+    fn try_from(raw: RawCloseAllPositionsResponse) -> Result<Self, Self::Error> {
+        Ok(CloseAllPositionsResponse {
+            body: OrderResult::from_status(raw.status, raw.body)?,
+            symbol: raw.symbol,
+            status: raw.status,
+        })
+    }
+}
+
+impl CloseAllPositionsResponse {
+    /// splits the responses into the closing orders and the classified errors of the
+    /// positions that failed to close, each paired with its symbol.
+    #[allow(clippy::type_complexity)]
+    pub fn split(
+        responses: Vec<CloseAllPositionsResponse>,
+    ) -> (Vec<(String, OrderResponse)>, Vec<(String, AlpacaError)>) {
+        let mut closed = vec![];
+        let mut failed = vec![];
+        for response in responses {
+            match response.body.into_result() {
+                Ok(order) => closed.push((response.symbol, order)),
+                Err(error) => failed.push((
+                    response.symbol,
+                    AlpacaError::from_status(response.status, error),
+                )),
+            }
+        }
+        (closed, failed)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::errors::AlpacaError;
+    use crate::positions::{CloseAllPositionsResponse, OpenPosition};
+
+    #[test]
+    fn position_parses() {
+        let input = r#"
+        {
+            "asset_id": "3fa85f64-5717-4562-b3fc-2c963f66afa6",
+            "symbol": "AAPL",
+            "exchange": "NYSE",
+            "asset_class": "us_equity",
+            "avg_entry_price": "12.5",
+            "qty": "105.0",
+            "qty_available": "0.0",
+            "side": "long",
+            "market_value": "1020.5",
+            "cost_basis": "1005.0",
+            "unrealized_pl": "15.0",
+            "unrealized_plpc": "1.0",
+            "unrealized_intraday_pl": "1.5",
+            "unrealized_intraday_plpc": "0.4",
+            "current_price": "12.0",
+            "lastday_price": "11.23",
+            "change_today": "0.5",
+            "asset_marginable": true
+        }
+        "#;
+
+        let parsed = serde_json::from_str::<OpenPosition>(input);
+        assert!(parsed.is_ok());
+
+        // Testing a short position as well.
+
+        let short_input = r#"
+        {
+            "asset_id":"02ee28f8-d7a5-41ef-b212-a66d8dd85c4d",
+            "symbol":"PENN",
+            "exchange":"NASDAQ",
+            "asset_class":"us_equity",
+            "avg_entry_price":"18.36",
+            "qty":"-100",
+            "qty_available":"-100",
+            "side":"short",
+            "market_value":"-1821.0",
+            "cost_basis":"-1836.0",
+            "unrealized_pl":"1.5",
+            "unrealized_plpc":"0.0081699346405229",
+            "unrealized_intraday_pl":"1.5",
+            "unrealized_intraday_plpc":"0.01",
+            "current_price":"18.21",
+            "lastday_price":"18.57",
+            "change_today":"-0.0193861066235864",
+            "asset_marginable":true
+        }
+        "#;
+
+        let parsed_short = serde_json::from_str::<OpenPosition>(short_input).expect("failed short");
+        println!("{parsed_short:#?}");
+    }
+
+    #[test]
+    fn close_all_parses_failed_closes() {
+        let input = r#"
+        [
+            {
+                "symbol": "AAPL",
+                "status": 200,
+                "body": {
+                    "id": "7b08df51-c1ac-453c-99f9-323a5f075f0d",
+                    "client_order_id": "5680c4bc-9ac1-4a12-a44c-df427ba53032",
+                    "created_at": "2023-12-12T22:31:24.668464435Z",
+                    "updated_at": "2023-12-12T22:31:24.668464435Z",
+                    "submitted_at": "2023-12-12T22:31:24.577215743Z",
+                    "filled_at": null,
+                    "expired_at": null,
+                    "canceled_at": null,
+                    "failed_at": null,
+                    "replaced_at": null,
+                    "replaced_by": null,
+                    "replaces": null,
+                    "asset_id": "b0b6dd9d-8b9b-48a9-ba46-b9d54906e415",
+                    "symbol": "AAPL",
+                    "asset_class": "us_equity",
+                    "notional": null,
+                    "qty": "2",
+                    "filled_qty": "0",
+                    "filled_avg_price": null,
+                    "order_class": "",
+                    "type": "market",
+                    "side": "sell",
+                    "time_in_force": "day",
+                    "limit_price": null,
+                    "stop_price": null,
+                    "status": "accepted",
+                    "extended_hours": false
+                }
+            },
+            {
+                "symbol": "PENN",
+                "status": 403,
+                "body": {
+                    "available": "0",
+                    "code": 40310000,
+                    "existing_qty": "100",
+                    "held_for_orders": "100",
+                    "message": "insufficient qty available for order (requested: 100, available: 0)",
+                    "symbol": "PENN"
+                }
+            }
+        ]
+        "#;
+
+        let responses = serde_json::from_str::<Vec<CloseAllPositionsResponse>>(input)
+            .expect("failed to parse close all response");
+        let (closed, failed) = CloseAllPositionsResponse::split(responses);
+
+        assert_eq!(closed.len(), 1);
+        assert_eq!(closed[0].0, "AAPL");
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].0, "PENN");
+        assert!(matches!(failed[0].1, AlpacaError::InsufficientQty(_)));
+        assert_eq!(failed[0].1.code(), 40310000);
+        assert_eq!(failed[0].1.available(), Some(0.0));
+        assert_eq!(failed[0].1.api_error().existing_qty, Some(100.0));
+        assert_eq!(failed[0].1.api_error().symbol.as_deref(), Some("PENN"));
+    }
+}
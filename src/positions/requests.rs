@@ -0,0 +1,236 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::serialize_opt_qty;
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "ts")]
+use ts_rs::TS;
+
+/// # ClosePositionError
+/// describes why a close position request would be rejected.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClosePositionError {
+    /// `qty` and `percentage` cannot be used together.
+    QtyAndPercentage,
+    /// the `qty` or `percentage` was zero, negative or not a number.
+    NotPositive { field: &'static str, value: f64 },
+    /// the percentage was above 100.
+    PercentageTooLarge(f64),
+    /// the query could not be url encoded.
+    Encode(String),
+}
+
+impl Display for ClosePositionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClosePositionError::QtyAndPercentage => {
+                write!(f, "qty and percentage cannot be used together")
+            }
+            ClosePositionError::NotPositive { field, value } => {
+                write!(f, "{field} must be greater than zero, got {value}")
+            }
+            ClosePositionError::PercentageTooLarge(percentage) => {
+                write!(f, "percentage must be at most 100, got {percentage}")
+            }
+            ClosePositionError::Encode(why) => write!(f, "failed to encode query: {why}"),
+        }
+    }
+}
+
+impl std::error::Error for ClosePositionError {}
+
+/// Alpaca expects crypto pairs without the slash in paths, e.g. `BTCUSD` for `BTC/USD`.
+fn path_symbol(symbol: &str) -> String {
+    symbol.replace('/', "")
+}
+
+/// # ClosePositionRequest
+/// closes (liquidates) a single position (DELETE /v2/positions/{symbol_or_asset_id}).
+/// Without `qty` or `percentage` the whole position is closed.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct ClosePositionRequest {
+    #[serde(skip)]
+    pub symbol_or_asset_id: String,
+    /// the number of shares to liquidate. Cannot be used with `percentage`.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_qty"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub qty: Option<f64>,
+    /// the percent of the position to liquidate, up to 100. Cannot be used with `qty`.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_qty"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::NumberString>")
+    )]
+    pub percentage: Option<f64>,
+}
+
+impl ClosePositionRequest {
+    /// closes the whole position.
+    pub fn all(symbol_or_asset_id: impl Into<String>) -> Self {
+        Self {
+            symbol_or_asset_id: symbol_or_asset_id.into(),
+            qty: None,
+            percentage: None,
+        }
+    }
+
+    /// closes `qty` shares of the position.
+    pub fn qty(symbol_or_asset_id: impl Into<String>, qty: f64) -> Self {
+        Self {
+            qty: Some(qty),
+            ..Self::all(symbol_or_asset_id)
+        }
+    }
+
+    /// closes `percentage` percent of the position.
+    pub fn percentage(symbol_or_asset_id: impl Into<String>, percentage: f64) -> Self {
+        Self {
+            percentage: Some(percentage),
+            ..Self::all(symbol_or_asset_id)
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), ClosePositionError> {
+        let (field, value) = match (self.qty, self.percentage) {
+            (Some(_), Some(_)) => return Err(ClosePositionError::QtyAndPercentage),
+            (None, None) => return Ok(()),
+            (Some(qty), None) => ("qty", qty),
+            (None, Some(percentage)) => ("percentage", percentage),
+        };
+
+        if !(value.is_finite() && value > 0.0) {
+            return Err(ClosePositionError::NotPositive { field, value });
+        }
+        if self.percentage.is_some() && value > 100.0 {
+            return Err(ClosePositionError::PercentageTooLarge(value));
+        }
+        Ok(())
+    }
+
+    pub fn path(&self) -> String {
+        format!("/v2/positions/{}", path_symbol(&self.symbol_or_asset_id))
+    }
+
+    /// validates the request and encodes it as a url query string, without the leading `?`.
+    pub fn to_query_string(&self) -> Result<String, ClosePositionError> {
+        self.validate()?;
+        serde_urlencoded::to_string(self).map_err(|why| ClosePositionError::Encode(why.to_string()))
+    }
+}
+
+/// # CloseAllPositionsRequest
+/// closes every open position (DELETE /v2/positions).
+/// Each position's result is a [`super::CloseAllPositionsResponse`].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct CloseAllPositionsRequest {
+    /// if true, cancels all open orders before liquidating the positions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub cancel_orders: Option<bool>,
+}
+
+impl CloseAllPositionsRequest {
+    /// encodes the request as a url query string, without the leading `?`.
+    pub fn to_query_string(&self) -> Result<String, serde_urlencoded::ser::Error> {
+        serde_urlencoded::to_string(self)
+    }
+}
+
+/// # ExerciseOptionRequest
+/// exercises a held option contract (POST /v2/positions/{symbol_or_contract_id}/exercise).
+/// The request has no body.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExerciseOptionRequest {
+    pub symbol_or_contract_id: String,
+}
+
+impl ExerciseOptionRequest {
+    pub fn new(symbol_or_contract_id: impl Into<String>) -> Self {
+        Self {
+            symbol_or_contract_id: symbol_or_contract_id.into(),
+        }
+    }
+
+    pub fn path(&self) -> String {
+        format!("/v2/positions/{}/exercise", self.symbol_or_contract_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CloseAllPositionsRequest, ClosePositionError, ClosePositionRequest, ExerciseOptionRequest,
+    };
+
+    #[test]
+    fn close_position_encodes() {
+        let request = ClosePositionRequest::percentage("BTC/USD", 50.0);
+        assert_eq!(request.path(), "/v2/positions/BTCUSD");
+        assert_eq!(request.to_query_string(), Ok("percentage=50".to_string()));
+
+        let request = ClosePositionRequest::qty("AAPL", 2.5);
+        assert_eq!(request.to_query_string(), Ok("qty=2.5".to_string()));
+
+        let request = ClosePositionRequest::all("AAPL");
+        assert_eq!(request.to_query_string(), Ok(String::new()));
+    }
+
+    #[test]
+    fn close_position_validates() {
+        let request = ClosePositionRequest {
+            qty: Some(1.0),
+            ..ClosePositionRequest::percentage("AAPL", 10.0)
+        };
+        assert_eq!(
+            request.to_query_string(),
+            Err(ClosePositionError::QtyAndPercentage)
+        );
+
+        let request = ClosePositionRequest::percentage("AAPL", 150.0);
+        assert_eq!(
+            request.validate(),
+            Err(ClosePositionError::PercentageTooLarge(150.0))
+        );
+
+        let request = ClosePositionRequest::qty("AAPL", 0.0);
+        assert_eq!(
+            request.validate(),
+            Err(ClosePositionError::NotPositive {
+                field: "qty",
+                value: 0.0
+            })
+        );
+    }
+
+    #[test]
+    fn close_all_and_exercise_encode() {
+        let request = CloseAllPositionsRequest {
+            cancel_orders: Some(true),
+        };
+        assert_eq!(
+            request.to_query_string().expect("failed to encode"),
+            "cancel_orders=true"
+        );
+
+        let request = ExerciseOptionRequest::new("AAPL250620C00100000");
+        assert_eq!(request.path(), "/v2/positions/AAPL250620C00100000/exercise");
+    }
+}
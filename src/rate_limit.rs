@@ -0,0 +1,274 @@
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+use http_serde::http::HeaderMap;
+use time::OffsetDateTime;
+
+pub const LIMIT_HEADER: &str = "x-ratelimit-limit";
+pub const REMAINING_HEADER: &str = "x-ratelimit-remaining";
+pub const RESET_HEADER: &str = "x-ratelimit-reset";
+
+/// # RateLimitError
+/// a rate limit header that is missing or could not be parsed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RateLimitError {
+    MissingHeader(&'static str),
+    InvalidHeader(&'static str),
+}
+
+impl Display for RateLimitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RateLimitError::MissingHeader(header) => write!(f, "missing header {header}"),
+            RateLimitError::InvalidHeader(header) => write!(f, "invalid value for header {header}"),
+        }
+    }
+}
+
+impl std::error::Error for RateLimitError {}
+
+/// # RateLimit
+/// the rate limit state Alpaca reports in the `X-RateLimit-*` headers of every response.
+/// * `limit`: the number of requests allowed per minute.
+/// * `remaining`: the number of requests left in the current window.
+/// * `reset`: when the window resets.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateLimit {
+    pub limit: u32,
+    pub remaining: u32,
+    pub reset: OffsetDateTime,
+}
+
+/// reads a header as an integer.
+fn parse_header<T: std::str::FromStr>(
+    headers: &HeaderMap,
+    name: &'static str,
+) -> Result<T, RateLimitError> {
+    let value = headers
+        .get(name)
+        .ok_or(RateLimitError::MissingHeader(name))?;
+
+    value
+        .to_str()
+        .ok()
+        .and_then(|value| value.trim().parse::<T>().ok())
+        .ok_or(RateLimitError::InvalidHeader(name))
+}
+
+impl RateLimit {
+    pub fn from_headers(headers: &HeaderMap) -> Result<RateLimit, RateLimitError> {
+        let reset = parse_header::<i64>(headers, RESET_HEADER)?;
+
+        Ok(RateLimit {
+            limit: parse_header(headers, LIMIT_HEADER)?,
+            remaining: parse_header(headers, REMAINING_HEADER)?,
+            reset: OffsetDateTime::from_unix_timestamp(reset)
+                .map_err(|_| RateLimitError::InvalidHeader(RESET_HEADER))?,
+        })
+    }
+}
+
+/// # RateBudget
+/// A client-agnostic token bucket for spacing out requests under Alpaca's per-minute limit.
+/// Call [`RateBudget::try_acquire`] before each request and wait for the returned duration
+/// when it is denied, then feed each response's [`RateLimit`] to [`RateBudget::observe`]
+/// so the bucket follows what the server has actually counted.
+///
+/// When several processes share one API key, give each of them a share of the limit
+/// with [`RateBudget::shared`] so together they stay under it.
+#[derive(Debug, Clone)]
+pub struct RateBudget {
+    capacity: f64,
+    refill_per_second: f64,
+    shares: u32,
+    tokens: f64,
+    updated: Instant,
+    blocked_until: Option<Instant>,
+}
+
+impl RateBudget {
+    /// a budget for a key allowed `requests_per_minute`.
+    pub fn new(requests_per_minute: u32) -> Self {
+        Self::shared(requests_per_minute, 1)
+    }
+
+    /// a budget for one of `shares` processes using the same key.
+    pub fn shared(requests_per_minute: u32, shares: u32) -> Self {
+        Self::shared_at(requests_per_minute, shares, Instant::now())
+    }
+
+    /// like `shared`, with the bucket starting full at `now`.
+    /// A share below one request per minute still holds one token and refills it slowly,
+    /// and a limit of zero is treated as one request per minute.
+    pub fn shared_at(requests_per_minute: u32, shares: u32, now: Instant) -> Self {
+        let shares = shares.max(1);
+        let per_minute = f64::from(requests_per_minute.max(1)) / f64::from(shares);
+        let capacity = per_minute.max(1.0);
+        Self {
+            capacity,
+            refill_per_second: per_minute / 60.0,
+            shares,
+            tokens: capacity,
+            updated: now,
+            blocked_until: None,
+        }
+    }
+
+    /// adds the tokens earned since the last update.
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_per_second).min(self.capacity);
+        self.updated = now;
+
+        if self.blocked_until.is_some_and(|until| now >= until) {
+            self.blocked_until = None;
+            self.tokens = self.capacity;
+        }
+    }
+
+    /// how long to wait before the next request may be sent, zero if it can be sent now.
+    pub fn wait_time(&mut self) -> Duration {
+        self.wait_time_at(Instant::now())
+    }
+
+    pub fn wait_time_at(&mut self, now: Instant) -> Duration {
+        self.refill(now);
+
+        if let Some(until) = self.blocked_until {
+            return until.saturating_duration_since(now);
+        }
+        if self.tokens >= 1.0 {
+            return Duration::ZERO;
+        }
+        Duration::from_secs_f64((1.0 - self.tokens) / self.refill_per_second)
+    }
+
+    /// takes a token for a request, or returns how long to wait for one.
+    pub fn try_acquire(&mut self) -> Result<(), Duration> {
+        self.try_acquire_at(Instant::now())
+    }
+
+    pub fn try_acquire_at(&mut self, now: Instant) -> Result<(), Duration> {
+        let wait = self.wait_time_at(now);
+        if wait.is_zero() {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(wait)
+        }
+    }
+
+    /// syncs the bucket with the server's count. Never adds tokens, and when the server
+    /// says the window is used up, blocks until it resets.
+    pub fn observe(&mut self, rate_limit: &RateLimit) {
+        self.observe_at(rate_limit, Instant::now(), OffsetDateTime::now_utc());
+    }
+
+    /// like `observe`, with `now` and `wall_now` being the same moment on both clocks.
+    pub fn observe_at(&mut self, rate_limit: &RateLimit, now: Instant, wall_now: OffsetDateTime) {
+        self.refill(now);
+
+        let remaining = f64::from(rate_limit.remaining) / f64::from(self.shares);
+        self.tokens = self.tokens.min(remaining);
+
+        if rate_limit.remaining == 0 {
+            let until_reset = (rate_limit.reset - wall_now).max(time::Duration::ZERO);
+            let until_reset = Duration::try_from(until_reset).unwrap_or_default();
+            self.blocked_until = Some(now + until_reset);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use http_serde::http::{HeaderMap, HeaderValue};
+    use time::macros::datetime;
+
+    use super::{RateBudget, RateLimit, RateLimitError};
+
+    #[test]
+    fn parses_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("X-RateLimit-Limit", HeaderValue::from_static("200"));
+        headers.insert("X-RateLimit-Remaining", HeaderValue::from_static("199"));
+        headers.insert("X-RateLimit-Reset", HeaderValue::from_static("1722000600"));
+
+        let got = RateLimit::from_headers(&headers).expect("failed to parse headers");
+        assert_eq!(
+            got,
+            RateLimit {
+                limit: 200,
+                remaining: 199,
+                reset: datetime!(2024-07-26 13:30 UTC),
+            }
+        );
+
+        headers.insert("X-RateLimit-Remaining", HeaderValue::from_static("lots"));
+        assert_eq!(
+            RateLimit::from_headers(&headers),
+            Err(RateLimitError::InvalidHeader("x-ratelimit-remaining"))
+        );
+
+        headers.remove("X-RateLimit-Limit");
+        assert_eq!(
+            RateLimit::from_headers(&headers),
+            Err(RateLimitError::MissingHeader("x-ratelimit-limit"))
+        );
+    }
+
+    #[test]
+    fn budget_spaces_requests() {
+        let start = Instant::now();
+        let mut budget = RateBudget::shared_at(120, 2, start);
+
+        for _ in 0..60 {
+            assert!(budget.try_acquire_at(start).is_ok());
+        }
+        // one request per second is refilled for a 60 request share.
+        assert_eq!(budget.try_acquire_at(start), Err(Duration::from_secs(1)));
+        assert!(budget
+            .try_acquire_at(start + Duration::from_secs(1))
+            .is_ok());
+    }
+
+    #[test]
+    fn small_shares_still_refill() {
+        let start = Instant::now();
+        // three requests a minute over four processes is one request every 80 seconds.
+        let mut budget = RateBudget::shared_at(3, 4, start);
+        assert!(budget.try_acquire_at(start).is_ok());
+        assert_eq!(budget.try_acquire_at(start), Err(Duration::from_secs(80)));
+        assert!(budget
+            .try_acquire_at(start + Duration::from_secs(80))
+            .is_ok());
+        assert!(budget
+            .try_acquire_at(start + Duration::from_secs(3600))
+            .is_ok());
+
+        let mut budget = RateBudget::new(0);
+        assert!(budget.try_acquire_at(Instant::now()).is_ok());
+        assert!(budget.try_acquire_at(Instant::now()).is_err());
+    }
+
+    #[test]
+    fn budget_follows_server() {
+        let start = Instant::now();
+        let wall_start = datetime!(2024-07-26 13:29:30 UTC);
+        let mut budget = RateBudget::shared_at(200, 1, start);
+
+        let rate_limit = RateLimit {
+            limit: 200,
+            remaining: 0,
+            reset: datetime!(2024-07-26 13:30 UTC),
+        };
+        budget.observe_at(&rate_limit, start, wall_start);
+
+        assert_eq!(budget.wait_time_at(start), Duration::from_secs(30));
+        assert_eq!(
+            budget.wait_time_at(start + Duration::from_secs(30)),
+            Duration::ZERO
+        );
+    }
+}
@@ -0,0 +1,1027 @@
+//! checks that every wire type survives a round trip through the format Alpaca uses:
+//! json bodies for requests and responses, url encoding for query strings.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use proptest::collection::{hash_map, vec};
+use proptest::option;
+use proptest::prelude::*;
+use proptest::sample::select;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use time::{Date, Duration, OffsetDateTime};
+use uuid::Uuid;
+
+use crate::broker::accounts::BrokerAccount;
+use crate::broker::funding::Transfer;
+use crate::broker::journals::Journal;
+use crate::errors::{AlpacaError, ApiError, ErrorMessage};
+use crate::market_data::crypto::{CryptoBar, LatestCryptoBars};
+use crate::market_data::stock::{Bar, Bars, LatestTrades, Trade};
+use crate::orders::{
+    AssetClass, CancelAllOrdersResponse, ListOrdersQuery, OrderClass, OrderEvent, OrderLegRequest,
+    OrderQueryStatus, OrderRequest, OrderResponse, OrderResult, OrderSide, OrderStatus, OrderType,
+    PositionIntent, ReplaceOrderRequest, SortDirection, StopLoss, TakeProfit, TimeInForce,
+    TradeUpdate,
+};
+use crate::positions::{
+    CloseAllPositionsRequest, CloseAllPositionsResponse, OpenPosition, PositionSide,
+};
+use crate::trading::portfolio_history::{HistoryFrame, PortfolioHistory, Timeframe};
+use crate::trading::portfolio_history_query::{
+    IntradayReporting, Period, PeriodUnit, PnlReset, PortfolioHistoryQuery,
+};
+
+/// a filled trailing stop order.
+pub(crate) const ORDER_RESPONSE: &str = r#"
+    {
+        "id": "61e69015-8549-4bfd-b9c3-01e75843f47d",
+        "client_order_id": "eb9e2aaa-f71a-4f51-b5b4-52a6c565dad4",
+        "created_at": "2021-03-16T18:38:01.942282Z",
+        "updated_at": "2021-03-16T18:38:01.942282Z",
+        "submitted_at": "2021-03-16T18:38:01.937734Z",
+        "filled_at": "2021-03-16T18:38:02.012345Z",
+        "expired_at": null,
+        "canceled_at": null,
+        "failed_at": null,
+        "replaced_at": null,
+        "replaced_by": null,
+        "replaces": null,
+        "asset_id": "b0b6dd9d-8b9b-48a9-ba46-b9d54906e415",
+        "symbol": "AAPL",
+        "asset_class": "us_equity",
+        "notional": null,
+        "qty": "100",
+        "filled_qty": "100",
+        "filled_avg_price": "121.03",
+        "order_class": "",
+        "type": "trailing_stop",
+        "side": "sell",
+        "position_intent": "sell_to_close",
+        "time_in_force": "day",
+        "limit_price": null,
+        "stop_price": "118.57",
+        "status": "filled",
+        "extended_hours": false,
+        "legs": null,
+        "trail_percent": null,
+        "trail_price": "2.46",
+        "hwm": "121.03",
+        "subtag": null,
+        "source": null
+    }
+    "#;
+
+/// a long position.
+pub(crate) const OPEN_POSITION: &str = r#"
+    {
+        "asset_id": "904837e3-3b76-47ec-b432-046db621571b",
+        "symbol": "AAPL",
+        "exchange": "NASDAQ",
+        "asset_class": "us_equity",
+        "avg_entry_price": "100.5",
+        "qty": "5",
+        "qty_available": "4",
+        "side": "long",
+        "market_value": "602.5",
+        "cost_basis": "502.5",
+        "unrealized_pl": "100",
+        "unrealized_plpc": "0.199",
+        "unrealized_intraday_pl": "10.25",
+        "unrealized_intraday_plpc": "0.0084",
+        "current_price": "120.5",
+        "lastday_price": "119.45",
+        "change_today": "0.0084",
+        "asset_marginable": true
+    }
+    "#;
+
+/// a completed outgoing wire transfer.
+pub(crate) const TRANSFER: &str = r#"
+    {
+        "id": "be3c368a-4c7c-4384-808e-f02c9f5a8afe",
+        "account_id": "0d969814-40d6-4b2b-99ac-2e37427f692f",
+        "relationship_id": null,
+        "bank_id": "6e9c5a3f-1f7b-4c3e-9a0d-2b8f4d1e7c55",
+        "type": "wire",
+        "status": "COMPLETE",
+        "reason": null,
+        "amount": "5000",
+        "requested_amount": "5000",
+        "fee": "0",
+        "fee_payment_method": "invoice",
+        "direction": "OUTGOING",
+        "additional_information": "rent",
+        "created_at": "2024-07-24T13:40:02.106941Z",
+        "updated_at": "2024-07-25T09:12:44Z",
+        "expires_at": null,
+        "hold_until": null
+    }
+    "#;
+
+/// a queued cash journal.
+pub(crate) const JOURNAL: &str = r#"
+    {
+        "id": "d5f7a9e2-52f0-4b5e-8c2b-1b8f2c8e6a41",
+        "entry_type": "JNLC",
+        "from_account": "8f8c8cee-2591-4f83-be12-82c659b5e748",
+        "to_account": "0d969814-40d6-4b2b-99ac-2e37427f692f",
+        "symbol": null,
+        "qty": null,
+        "price": null,
+        "status": "queued",
+        "settle_date": null,
+        "system_date": "2024-07-24",
+        "net_amount": "25.5",
+        "description": null,
+        "currency": "USD",
+        "transmitter_name": null,
+        "transmitter_account_number": null,
+        "transmitter_address": null,
+        "transmitter_financial_institution": null,
+        "transmitter_timestamp": "2024-07-24T13:45:00Z"
+    }
+    "#;
+
+/// serializes to json and back, failing unless the value is unchanged.
+fn json_round_trip<T>(value: &T) -> Result<(), TestCaseError>
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    let json = serde_json::to_string(value).map_err(|why| TestCaseError::fail(why.to_string()))?;
+    let parsed: T = serde_json::from_str(&json)
+        .map_err(|why| TestCaseError::fail(format!("{why} in {json}")))?;
+    prop_assert_eq!(&parsed, value, "json: {}", json);
+    Ok(())
+}
+
+/// url encodes and back, failing unless the value is unchanged.
+fn query_round_trip<T>(value: &T) -> Result<(), TestCaseError>
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    let query =
+        serde_urlencoded::to_string(value).map_err(|why| TestCaseError::fail(why.to_string()))?;
+    let parsed: T = serde_urlencoded::from_str(&query)
+        .map_err(|why| TestCaseError::fail(format!("{why} in {query}")))?;
+    prop_assert_eq!(&parsed, value, "query: {}", query);
+    Ok(())
+}
+
+/// parses a fixture, serializes it again and checks every field keeps its json type and value,
+/// e.g. that numbers Alpaca sends as strings are written back as the same strings.
+fn assert_keeps_wire_format<T>(fixture: &str)
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    fn assert_same_shape(path: &str, wanted: &Value, got: &Value) {
+        match (wanted, got) {
+            (Value::Object(wanted), Value::Object(got)) => {
+                let mut wanted_keys: Vec<_> = wanted.keys().collect();
+                let mut got_keys: Vec<_> = got.keys().collect();
+                wanted_keys.sort();
+                got_keys.sort();
+                assert_eq!(wanted_keys, got_keys, "keys of {path}");
+                for (key, value) in wanted {
+                    assert_same_shape(&format!("{path}.{key}"), value, &got[key]);
+                }
+            }
+            (Value::Array(wanted), Value::Array(got)) => {
+                assert_eq!(wanted.len(), got.len(), "length of {path}");
+                for (idx, (wanted, got)) in wanted.iter().zip(got).enumerate() {
+                    assert_same_shape(&format!("{path}[{idx}]"), wanted, got);
+                }
+            }
+            // integers and floats may be written differently, e.g. `28999` as `28999.0`.
+            (Value::Number(number), Value::Number(other)) if number.as_f64() == other.as_f64() => {}
+            (Value::String(_), Value::String(_))
+            | (Value::Bool(_), Value::Bool(_))
+            | (Value::Null, Value::Null)
+                if wanted == got => {}
+            _ => panic!("{path} was {wanted} but is written as {got}"),
+        }
+    }
+
+    let wanted: Value = serde_json::from_str(fixture).expect("fixture is not json");
+    let parsed: T = serde_json::from_value(wanted.clone()).expect("failed to parse fixture");
+    let got = serde_json::to_value(&parsed).expect("failed to serialize");
+    assert_same_shape("$", &wanted, &got);
+
+    let reparsed: T = serde_json::from_value(got).expect("failed to parse serialized value");
+    assert_eq!(reparsed, parsed);
+}
+
+fn finite() -> impl Strategy<Value = f64> {
+    any::<f64>().prop_filter("finite", |value| value.is_finite())
+}
+
+fn timestamp() -> impl Strategy<Value = OffsetDateTime> {
+    // 1970 through 2099, with nanoseconds.
+    (0..4_102_444_800i64, 0..1_000_000_000i64).prop_map(|(seconds, nanos)| {
+        OffsetDateTime::from_unix_timestamp(seconds).expect("in range")
+            + Duration::nanoseconds(nanos)
+    })
+}
+
+fn uuid() -> impl Strategy<Value = Uuid> {
+    any::<u128>().prop_map(Uuid::from_u128)
+}
+
+fn symbol() -> impl Strategy<Value = String> {
+    "[A-Z]{1,5}(/USD)?"
+}
+
+fn number_string() -> impl Strategy<Value = String> {
+    finite().prop_map(|value| value.to_string())
+}
+
+fn order_side() -> impl Strategy<Value = OrderSide> {
+    select(vec![OrderSide::Buy, OrderSide::Sell])
+}
+
+fn order_type() -> impl Strategy<Value = OrderType> {
+    select(vec![
+        OrderType::Market,
+        OrderType::Limit,
+        OrderType::Stop,
+        OrderType::StopLimit,
+        OrderType::TrailingStop,
+    ])
+}
+
+fn time_in_force() -> impl Strategy<Value = TimeInForce> {
+    select(vec![
+        TimeInForce::Day,
+        TimeInForce::GTC,
+        TimeInForce::OPG,
+        TimeInForce::CLS,
+        TimeInForce::IOC,
+        TimeInForce::FOK,
+    ])
+}
+
+fn position_intent() -> impl Strategy<Value = PositionIntent> {
+    select(vec![
+        PositionIntent::BuyToOpen,
+        PositionIntent::BuyToClose,
+        PositionIntent::SellToOpen,
+        PositionIntent::SellToClose,
+    ])
+}
+
+fn asset_class() -> impl Strategy<Value = AssetClass> {
+    select(vec![
+        AssetClass::UsEquity,
+        AssetClass::UsOption,
+        AssetClass::Crypto,
+    ])
+}
+
+fn order_status() -> impl Strategy<Value = OrderStatus> {
+    select(vec![
+        OrderStatus::New,
+        OrderStatus::PartiallyFilled,
+        OrderStatus::Filled,
+        OrderStatus::DoneForDay,
+        OrderStatus::Canceled,
+        OrderStatus::Expired,
+        OrderStatus::Replaced,
+        OrderStatus::PendingCancel,
+        OrderStatus::PendingReplace,
+        OrderStatus::Accepted,
+        OrderStatus::PendingNew,
+        OrderStatus::AcceptedForBidding,
+        OrderStatus::Stopped,
+        OrderStatus::Rejected,
+        OrderStatus::Suspended,
+        OrderStatus::Calculated,
+    ])
+}
+
+fn order_event() -> impl Strategy<Value = OrderEvent> {
+    select(vec![
+        OrderEvent::PendingNew,
+        OrderEvent::New,
+        OrderEvent::PartialFill,
+        OrderEvent::Fill,
+        OrderEvent::DoneForDay,
+        OrderEvent::Canceled,
+        OrderEvent::Expired,
+        OrderEvent::Replaced,
+        OrderEvent::Rejected,
+        OrderEvent::PendingCancel,
+        OrderEvent::PendingReplace,
+        OrderEvent::Stopped,
+        OrderEvent::Suspended,
+        OrderEvent::Calculated,
+        OrderEvent::OrderCancelRejected,
+        OrderEvent::OrderReplaceRejected,
+        OrderEvent::TradeBust,
+        OrderEvent::TradeCorrect,
+        OrderEvent::Unknown("restated".to_string()),
+        OrderEvent::Unknown("held".to_string()),
+    ])
+}
+
+/// an optional field Alpaca sends as a string encoded number or null.
+fn opt_number_value() -> impl Strategy<Value = Option<Value>> {
+    option::of(number_string().prop_map(Value::String))
+}
+
+prop_compose! {
+    fn order_leg_request()(
+        symbol in symbol(),
+        ratio_qty in finite(),
+        side in order_side(),
+        position_intent in option::of(position_intent()),
+    ) -> OrderLegRequest {
+        OrderLegRequest { symbol, ratio_qty, side, position_intent }
+    }
+}
+
+prop_compose! {
+    fn order_request()(
+        (symbol, qty, notional, side, order_type, time_in_force) in (
+            option::of(symbol()),
+            option::of(finite()),
+            option::of(finite()),
+            option::of(order_side()),
+            order_type(),
+            time_in_force(),
+        ),
+        (limit_price, stop_price, trail_price, trail_percent) in (
+            option::of(finite()),
+            option::of(finite()),
+            option::of(finite()),
+            option::of(finite()),
+        ),
+        (extended_hours, client_order_id, order_class) in (
+            option::of(any::<bool>()),
+            option::of("[a-z0-9-]{1,48}"),
+            option::of(select(vec![
+                OrderClass::Simple,
+                OrderClass::Bracket,
+                OrderClass::Oco,
+                OrderClass::Oto,
+                OrderClass::Mleg,
+            ])),
+        ),
+        (take_profit, stop_loss, position_intent, legs) in (
+            option::of(finite().prop_map(|limit_price| TakeProfit { limit_price })),
+            option::of((finite(), option::of(finite())).prop_map(|(stop_price, limit_price)| {
+                StopLoss { stop_price, limit_price }
+            })),
+            option::of(position_intent()),
+            option::of(vec(order_leg_request(), 1..4)),
+        ),
+    ) -> OrderRequest {
+        OrderRequest {
+            symbol,
+            qty,
+            notional,
+            side,
+            order_type,
+            time_in_force,
+            limit_price,
+            stop_price,
+            trail_price,
+            trail_percent,
+            extended_hours,
+            client_order_id,
+            order_class,
+            take_profit,
+            stop_loss,
+            position_intent,
+            legs,
+        }
+    }
+}
+
+prop_compose! {
+    fn replace_order_request()(
+        qty in option::of(finite()),
+        time_in_force in option::of(time_in_force()),
+        limit_price in option::of(finite()),
+        stop_price in option::of(finite()),
+        trail in option::of(finite()),
+        client_order_id in option::of("[a-z0-9-]{1,48}"),
+    ) -> ReplaceOrderRequest {
+        ReplaceOrderRequest { qty, time_in_force, limit_price, stop_price, trail, client_order_id }
+    }
+}
+
+prop_compose! {
+    fn order_response()(
+        (id, client_order_id, created_at, updated_at, submitted_at) in (
+            uuid(),
+            "[a-z0-9-]{1,48}",
+            timestamp(),
+            timestamp(),
+            timestamp(),
+        ),
+        (filled_at, expired_at, canceled_at, failed_at, replaced_at) in (
+            option::of(timestamp()),
+            option::of(timestamp()),
+            option::of(timestamp()),
+            option::of(timestamp()),
+            option::of(timestamp()),
+        ),
+        (replaced_by, replaces, asset_id, symbol, asset_class) in (
+            option::of(uuid().prop_map(|id| id.to_string())),
+            option::of(uuid().prop_map(|id| id.to_string())),
+            uuid(),
+            symbol(),
+            asset_class(),
+        ),
+        (notional, qty, filled_qty, filled_avg_price, order_class) in (
+            option::of(number_string()),
+            option::of(finite()),
+            number_string(),
+            option::of(finite()),
+            select(vec!["", "simple", "bracket", "oco", "oto", "mleg"]),
+        ),
+        (order_type, order_side, time_in_force, limit_price, stop_price) in (
+            order_type(),
+            order_side(),
+            time_in_force(),
+            option::of(finite()),
+            option::of(finite()),
+        ),
+        (status, extended_hours, legs, position_intent, ratio_qty, deprecated_order_type) in (
+            order_status(),
+            any::<bool>(),
+            select(vec![None, Some(vec![])]),
+            option::of(position_intent()),
+            option::of(number_string()),
+            option::of(order_type()),
+        ),
+        (trail_percent, trail_price, hwm, subtag, source) in (
+            opt_number_value(),
+            opt_number_value(),
+            opt_number_value(),
+            option::of("[a-z]{1,8}".prop_map(Value::String)),
+            option::of("[a-z]{1,8}".prop_map(Value::String)),
+        ),
+    ) -> OrderResponse {
+        OrderResponse {
+            id,
+            client_order_id,
+            created_at,
+            updated_at,
+            submitted_at,
+            filled_at,
+            expired_at,
+            canceled_at,
+            failed_at,
+            replaced_at,
+            replaced_by,
+            replaces,
+            asset_id,
+            symbol,
+            asset_class,
+            notional,
+            qty,
+            filled_qty,
+            filled_avg_price,
+            order_class: order_class.to_string(),
+            order_type,
+            order_side,
+            time_in_force,
+            limit_price,
+            stop_price,
+            status,
+            extended_hours,
+            legs,
+            position_intent,
+            ratio_qty,
+            deprecated_order_type,
+            trail_percent,
+            trail_price,
+            hwm,
+            subtag,
+            source,
+            #[cfg(feature = "extra_fields")]
+            extra: Default::default(),
+        }
+    }
+}
+
+prop_compose! {
+    fn trade_update()(
+        event in order_event(),
+        execution_id in option::of(uuid()),
+        order in order_response(),
+        timestamp in option::of(timestamp()),
+        price in option::of(finite()),
+        qty in option::of(finite()),
+        position_qty in option::of(finite()),
+    ) -> TradeUpdate {
+        TradeUpdate {
+            event,
+            execution_id,
+            order,
+            timestamp,
+            price,
+            qty,
+            position_qty,
+            #[cfg(feature = "extra_fields")]
+            extra: Default::default(),
+        }
+    }
+}
+
+prop_compose! {
+    fn error_message()(
+        code in prop_oneof![400..600u32, 10_000_000..60_000_000u32],
+        message in "[a-z ]{0,40}",
+    ) -> ErrorMessage {
+        ErrorMessage { code, message }
+    }
+}
+
+/// a multi-status entry whose body matches its status.
+fn status_and_body() -> impl Strategy<Value = (u16, OrderResult)> {
+    prop_oneof![
+        order_response().prop_map(|order| (200, OrderResult::Order(Box::new(order)))),
+        (400..600u16, api_error()).prop_map(|(status, error)| (status, OrderResult::Error(error))),
+    ]
+}
+
+prop_compose! {
+    fn api_error()(
+        code in prop_oneof![Just(0u32), 10_000_000..60_000_000u32],
+        message in "[a-z ]{0,40}",
+        related_orders in vec(uuid().prop_map(|id| id.to_string()), 0..3),
+        (available, existing_qty, held_for_orders, buying_power, cost) in (
+            option::of(finite()),
+            option::of(finite()),
+            option::of(finite()),
+            option::of(finite()),
+            option::of(finite()),
+        ),
+        symbol in option::of(symbol()),
+        reject_reason in option::of("[a-z_]{1,24}"),
+    ) -> ApiError {
+        ApiError {
+            code,
+            message,
+            related_orders,
+            available,
+            existing_qty,
+            held_for_orders,
+            buying_power,
+            cost,
+            symbol,
+            reject_reason,
+            #[cfg(feature = "extra_fields")]
+            extra: Default::default(),
+        }
+    }
+}
+
+prop_compose! {
+    fn open_position()(
+        (asset_id, symbol, exchange, asset_class, avg_entry_price) in (
+            uuid(),
+            symbol(),
+            "[A-Z]{4}",
+            asset_class(),
+            option::of(finite()),
+        ),
+        (qty, qty_available, side, market_value, cost_basis, unrealized_pl) in (
+            finite(),
+            finite(),
+            select(vec![PositionSide::Long, PositionSide::Short]),
+            finite(),
+            finite(),
+            finite(),
+        ),
+        (unrealized_plpc, unrealized_intraday_pl, unrealized_intraday_plpc) in (
+            finite(),
+            finite(),
+            finite(),
+        ),
+        (current_price, lastday_price, change_today, asset_marginable) in (
+            finite(),
+            finite(),
+            finite(),
+            any::<bool>(),
+        ),
+    ) -> OpenPosition {
+        OpenPosition {
+            asset_id,
+            symbol,
+            exchange,
+            asset_class,
+            avg_entry_price,
+            qty,
+            qty_available,
+            side,
+            market_value,
+            cost_basis,
+            unrealized_pl,
+            unrealized_plpc,
+            unrealized_intraday_pl,
+            unrealized_intraday_plpc,
+            current_price,
+            lastday_price,
+            change_today,
+            asset_marginable,
+            #[cfg(feature = "extra_fields")]
+            extra: Default::default(),
+        }
+    }
+}
+
+prop_compose! {
+    fn crypto_bar()(
+        timestamp in timestamp(),
+        (open, high, low, close, volume, vwap) in (
+            finite(), finite(), finite(), finite(), finite(), finite(),
+        ),
+        trade_count in any::<i64>(),
+    ) -> CryptoBar {
+        CryptoBar { timestamp, open, high, low, close, volume, trade_count, vwap }
+    }
+}
+
+prop_compose! {
+    fn stock_bar()(
+        timestamp in timestamp(),
+        (open, high, low, close, vwap) in (finite(), finite(), finite(), finite(), finite()),
+        volume in any::<u64>(),
+        trade_count in any::<u64>(),
+    ) -> Bar {
+        Bar { timestamp, open, high, low, close, volume, trade_count, vwap }
+    }
+}
+
+prop_compose! {
+    fn trade()(
+        condition_flags in vec("[@A-Z]", 0..4),
+        trade_id in any::<i64>(),
+        price in finite(),
+        trade_size in any::<u32>(),
+        timestamp in timestamp().prop_map(|time| time.to_string()),
+        exchange_code in "[A-Z]",
+        tape in "[A-C]",
+    ) -> Trade {
+        Trade { condition_flags, trade_id, price, trade_size, timestamp, exchange_code, tape }
+    }
+}
+
+fn points(len: usize) -> impl Strategy<Value = Vec<Option<f64>>> {
+    vec(option::of(finite()), len)
+}
+
+fn portfolio_history() -> impl Strategy<Value = PortfolioHistory> {
+    (0..8usize).prop_flat_map(|len| {
+        (
+            vec(any::<i64>(), len),
+            (points(len), points(len), points(len)),
+            (finite(), "[0-9]{4}-[0-9]{2}-[0-9]{2}"),
+            select(vec!["1Min", "5Min", "15Min", "1H", "1D"]),
+            hash_map("[A-Z]{3}", points(len), 0..3),
+        )
+            .prop_map(
+                |(timestamp, (equity, profit_loss, profit_loss_pct), base, timeframe, cashflow)| {
+                    PortfolioHistory {
+                        timestamp,
+                        equity,
+                        profit_loss,
+                        profit_loss_pct,
+                        base_value: base.0,
+                        base_value_asof: base.1,
+                        timeframe: timeframe.to_string(),
+                        cashflow,
+                        #[cfg(feature = "extra_fields")]
+                        extra: Default::default(),
+                    }
+                },
+            )
+    })
+}
+
+prop_compose! {
+    fn history_frame()(
+        time in timestamp(),
+        equity in option::of(finite()),
+        profit_loss in option::of(finite()),
+        profit_loss_percent in option::of(finite()),
+        base_value in finite(),
+        base_value_as_of in "[0-9]{4}-[0-9]{2}-[0-9]{2}",
+        cashflow in hash_map("[A-Z]{3}", finite(), 0..3),
+    ) -> HistoryFrame {
+        HistoryFrame {
+            time,
+            equity,
+            profit_loss,
+            profit_loss_percent,
+            base_value,
+            base_value_as_of: Arc::from(base_value_as_of),
+            cashflow,
+        }
+    }
+}
+
+prop_compose! {
+    fn list_orders_query()(
+        status in option::of(select(vec![
+            OrderQueryStatus::Open,
+            OrderQueryStatus::Closed,
+            OrderQueryStatus::All,
+        ])),
+        limit in option::of(1..=500u32),
+        after in option::of(timestamp()),
+        until in option::of(timestamp()),
+        direction in option::of(select(vec![SortDirection::Asc, SortDirection::Desc])),
+        nested in option::of(any::<bool>()),
+        symbols in option::of(vec(symbol(), 1..4)),
+        side in option::of(order_side()),
+    ) -> ListOrdersQuery {
+        ListOrdersQuery { status, limit, after, until, direction, nested, symbols, side }
+    }
+}
+
+prop_compose! {
+    fn portfolio_history_query()(
+        period in option::of((any::<u32>(), select(vec![
+            PeriodUnit::Day,
+            PeriodUnit::Week,
+            PeriodUnit::Month,
+            PeriodUnit::Year,
+        ])).prop_map(|(amount, unit)| Period { amount, unit })),
+        timeframe in option::of(select(vec![
+            Timeframe::OneMinute,
+            Timeframe::FiveMinutes,
+            Timeframe::FifteenMinutes,
+            Timeframe::OneHour,
+            Timeframe::OneDay,
+        ])),
+        date_end in option::of((2_440_588..2_488_070i32).prop_map(|day| {
+            Date::from_julian_day(day).expect("in range")
+        })),
+        start in option::of(timestamp()),
+        end in option::of(timestamp()),
+        intraday_reporting in option::of(select(vec![
+            IntradayReporting::MarketHours,
+            IntradayReporting::ExtendedHours,
+            IntradayReporting::Continuous,
+        ])),
+        pnl_reset in option::of(select(vec![PnlReset::PerDay, PnlReset::NoReset])),
+        cashflow_types in option::of(vec("[A-Z]{3}", 1..4)),
+    ) -> PortfolioHistoryQuery {
+        PortfolioHistoryQuery {
+            period,
+            timeframe,
+            date_end,
+            start,
+            end,
+            intraday_reporting,
+            pnl_reset,
+            cashflow_types,
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn order_requests_round_trip(request in order_request()) {
+        json_round_trip(&request)?;
+    }
+
+    #[test]
+    fn replace_order_requests_round_trip(request in replace_order_request()) {
+        json_round_trip(&request)?;
+    }
+
+    #[test]
+    fn order_responses_round_trip(order in order_response()) {
+        json_round_trip(&order)?;
+        json_round_trip(&OrderResult::Order(Box::new(order)))?;
+    }
+
+    #[test]
+    fn trade_updates_round_trip(update in trade_update()) {
+        json_round_trip(&update)?;
+    }
+
+    #[test]
+    fn multi_status_responses_round_trip(
+        id in uuid(),
+        symbol in symbol(),
+        (status, body) in status_and_body(),
+    ) {
+        json_round_trip(&CancelAllOrdersResponse { id, status, body: body.clone() })?;
+        json_round_trip(&CloseAllPositionsResponse { symbol, status, body })?;
+    }
+
+    #[test]
+    fn errors_round_trip(error in api_error(), message in error_message()) {
+        json_round_trip(&error)?;
+        json_round_trip(&OrderResult::Error(error.clone()))?;
+        json_round_trip(&AlpacaError::from(error))?;
+        json_round_trip(&message)?;
+    }
+
+    #[test]
+    fn positions_round_trip(position in open_position()) {
+        json_round_trip(&position)?;
+    }
+
+    #[test]
+    fn market_data_round_trips(
+        crypto_bars in hash_map(symbol(), crypto_bar(), 0..3),
+        bars in hash_map(symbol(), vec(stock_bar(), 0..3), 0..3),
+        next_page_token in option::of("[a-zA-Z0-9]{1,16}"),
+        trades in hash_map(symbol(), trade(), 0..3),
+    ) {
+        json_round_trip(&LatestCryptoBars {
+            bars: crypto_bars,
+            #[cfg(feature = "extra_fields")]
+            extra: Default::default(),
+        })?;
+        json_round_trip(&Bars {
+            bars,
+            next_page_token,
+            #[cfg(feature = "extra_fields")]
+            extra: Default::default(),
+        })?;
+        json_round_trip(&LatestTrades {
+            trades,
+            #[cfg(feature = "extra_fields")]
+            extra: Default::default(),
+        })?;
+    }
+
+    #[test]
+    fn portfolio_history_round_trips(history in portfolio_history(), frame in history_frame()) {
+        json_round_trip(&history)?;
+        json_round_trip(&frame)?;
+    }
+
+    #[test]
+    fn queries_round_trip(
+        orders in list_orders_query(),
+        history in portfolio_history_query(),
+        cancel_orders in option::of(any::<bool>()),
+    ) {
+        query_round_trip(&orders)?;
+        query_round_trip(&history)?;
+        query_round_trip(&CloseAllPositionsRequest { cancel_orders })?;
+    }
+}
+
+#[test]
+fn order_response_keeps_wire_format() {
+    assert_keeps_wire_format::<OrderResponse>(ORDER_RESPONSE);
+}
+
+#[test]
+fn open_position_keeps_wire_format() {
+    assert_keeps_wire_format::<OpenPosition>(OPEN_POSITION);
+}
+
+#[test]
+fn market_data_keeps_wire_format() {
+    assert_keeps_wire_format::<LatestCryptoBars>(
+        r#"
+        {
+            "bars": {
+                "BTC/USD": {
+                    "t": "2022-05-27T10:18:00Z",
+                    "o": 28999,
+                    "h": 29003,
+                    "l": 28999,
+                    "c": 29003,
+                    "v": 0.01,
+                    "n": 4,
+                    "vw": 29001
+                }
+            }
+        }
+        "#,
+    );
+}
+
+#[test]
+fn portfolio_history_keeps_wire_format() {
+    assert_keeps_wire_format::<PortfolioHistory>(
+        r#"
+        {
+            "timestamp": [1721793600, 1721880000],
+            "equity": [null, 100129.67],
+            "profit_loss": [null, 0],
+            "profit_loss_pct": [null, 0],
+            "base_value": 100129.67,
+            "base_value_asof": "2024-07-24",
+            "timeframe": "1D",
+            "cashflow": {"DIV": [0, 12.5]}
+        }
+        "#,
+    );
+
+    let history: PortfolioHistory = serde_json::from_str(
+        r#"{"timestamp": [], "equity": [], "profit_loss": [], "profit_loss_pct": [],
+            "base_value": 0, "base_value_asof": "", "timeframe": "1D"}"#,
+    )
+    .expect("failed to parse history");
+    let json = serde_json::to_value(&history).expect("failed to serialize history");
+    assert_eq!(json.get("cashflow"), None);
+    assert_eq!(history.cashflow, HashMap::new());
+}
+
+#[test]
+fn broker_account_keeps_wire_format() {
+    assert_keeps_wire_format::<BrokerAccount>(
+        r#"
+        {
+            "id": "0d969814-40d6-4b2b-99ac-2e37427f692f",
+            "account_number": "682455281",
+            "status": "ACTIVE",
+            "crypto_status": null,
+            "currency": "USD",
+            "last_equity": "1520.75",
+            "created_at": "2024-07-24T13:30:12.347219Z",
+            "account_type": "trading",
+            "contact": {
+                "email_address": "cool_alpaca@example.com",
+                "street_address": ["20 N San Mateo Dr"],
+                "city": "San Mateo"
+            },
+            "identity": {
+                "given_name": "John",
+                "family_name": "Doe",
+                "date_of_birth": "1990-01-01",
+                "funding_source": ["employment_income", "savings"],
+                "liquid_net_worth_min": "10000"
+            },
+            "disclosures": {
+                "is_control_person": false,
+                "is_affiliated_exchange_or_finra": true,
+                "is_politically_exposed": false,
+                "immediate_family_exposed": false,
+                "context": [{"context_type": "AFFILIATE_FIRM", "company_name": "Alpaca"}]
+            },
+            "agreements": [
+                {
+                    "agreement": "customer_agreement",
+                    "signed_at": "2024-07-24T13:30:11Z",
+                    "ip_address": "185.13.21.99"
+                }
+            ],
+            "documents": [],
+            "trusted_contact": null,
+            "enabled_assets": ["us_equity", "crypto"]
+        }
+        "#,
+    );
+}
+
+#[test]
+fn transfer_keeps_wire_format() {
+    assert_keeps_wire_format::<Transfer>(TRANSFER);
+}
+
+#[test]
+fn journal_keeps_wire_format() {
+    assert_keeps_wire_format::<Journal>(JOURNAL);
+}
+
+#[test]
+fn error_message_keeps_wire_format() {
+    assert_keeps_wire_format::<ErrorMessage>(
+        r#"
+        {
+            "code": 40310000,
+            "message": "insufficient qty available for order (requested: 100, available: 0)"
+        }
+        "#,
+    );
+    assert_keeps_wire_format::<ErrorMessage>(r#"{ "code": 404, "message": "order not found" }"#);
+}
+
+#[test]
+fn close_all_positions_failure_keeps_wire_format() {
+    assert_keeps_wire_format::<Vec<CloseAllPositionsResponse>>(
+        r#"
+        [
+            {
+                "symbol": "PENN",
+                "status": 403,
+                "body": {
+                    "available": "0",
+                    "code": 40310000,
+                    "existing_qty": "100",
+                    "held_for_orders": "100",
+                    "message": "insufficient qty available for order (requested: 100, available: 0)",
+                    "symbol": "PENN"
+                }
+            }
+        ]
+        "#,
+    );
+}
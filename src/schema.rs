@@ -0,0 +1,310 @@
+//! JSON Schema for the wire types, for services outside Rust that validate Alpaca payloads.
+//! The schemas describe the json Alpaca sends and accepts, e.g. quantities are strings.
+
+use std::borrow::Cow;
+
+use schemars::generate::SchemaSettings;
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use crate::broker::accounts::{
+    AccountDocument, AccountStatus, AccountStatusEvent, AccountType, Agreement, AgreementType,
+    BrokerAccount, Contact, CreateAccountRequest, DisclosureContext, DisclosureContextType,
+    Disclosures, EmploymentStatus, FundingSource, Identity, TrustedContact, UpdateAccountRequest,
+};
+use crate::broker::funding::{
+    AchRelationship, AchRelationshipStatus, BankAccountType, BankCodeType, BankRelationship,
+    BankRelationshipStatus, CreateAchRelationshipRequest, CreateBankRequest, CreateTransferRequest,
+    FeePaymentMethod, Transfer, TransferDirection, TransferStatus, TransferStatusEvent,
+    TransferTiming, TransferType,
+};
+use crate::broker::journals::{
+    BatchJournalEntry, BatchJournalRequest, Journal, JournalEntryType, JournalRequest,
+    JournalStatus, ReverseBatchJournalEntry, ReverseBatchJournalRequest,
+};
+use crate::errors::{AlpacaError, ApiError, ErrorMessage};
+use crate::market_data::crypto::{CryptoBar, LatestCryptoBars};
+use crate::market_data::stock::{Bar, Bars, LatestTrades, Trade};
+use crate::orders::{
+    AssetClass, CancelAllOrdersResponse, ListOrdersQuery, OrderClass, OrderEvent, OrderLegRequest,
+    OrderQueryStatus, OrderRequest, OrderResponse, OrderResult, OrderSide, OrderStatus, OrderType,
+    PositionIntent, ReplaceOrderRequest, SortDirection, StopLoss, TakeProfit, TimeInForce,
+    TradeUpdate,
+};
+use crate::positions::{
+    CloseAllPositionsRequest, CloseAllPositionsResponse, ClosePositionRequest, OpenPosition,
+    PositionSide,
+};
+use crate::trading::portfolio_history::{HistoryFrame, PortfolioHistory, Timeframe};
+use crate::trading::portfolio_history_query::{
+    IntradayReporting, Period, PnlReset, PortfolioHistoryQuery,
+};
+
+/// the schema of a number Alpaca sends as a string, e.g. `"150.25"` or `"1e-5"`,
+/// matching the finite numbers `str::parse::<f64>` accepts.
+pub(crate) struct NumberString;
+
+impl JsonSchema for NumberString {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "NumberString".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "pattern": "^[-+]?([0-9]+(\\.[0-9]*)?|\\.[0-9]+)([eE][-+]?[0-9]+)?$"
+        })
+    }
+}
+
+/// the schema of an RFC 3339 timestamp, e.g. `"2024-07-24T13:30:00Z"`.
+pub(crate) struct DateTime;
+
+impl JsonSchema for DateTime {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "DateTime".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "format": "date-time"
+        })
+    }
+}
+
+/// the schema of a `YYYY-MM-DD` date.
+pub(crate) struct FullDate;
+
+impl JsonSchema for FullDate {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "FullDate".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "format": "date"
+        })
+    }
+}
+
+/// collects the schema of every type into `generator`'s definitions.
+fn add_definitions(generator: &mut SchemaGenerator) {
+    generator.subschema_for::<AccountStatus>();
+    generator.subschema_for::<AccountType>();
+    generator.subschema_for::<FundingSource>();
+    generator.subschema_for::<EmploymentStatus>();
+    generator.subschema_for::<AgreementType>();
+    generator.subschema_for::<DisclosureContextType>();
+    generator.subschema_for::<Contact>();
+    generator.subschema_for::<Identity>();
+    generator.subschema_for::<DisclosureContext>();
+    generator.subschema_for::<Disclosures>();
+    generator.subschema_for::<Agreement>();
+    generator.subschema_for::<TrustedContact>();
+    generator.subschema_for::<AccountDocument>();
+    generator.subschema_for::<CreateAccountRequest>();
+    generator.subschema_for::<UpdateAccountRequest>();
+    generator.subschema_for::<BrokerAccount>();
+    generator.subschema_for::<AccountStatusEvent>();
+
+    generator.subschema_for::<BankAccountType>();
+    generator.subschema_for::<AchRelationshipStatus>();
+    generator.subschema_for::<CreateAchRelationshipRequest>();
+    generator.subschema_for::<AchRelationship>();
+    generator.subschema_for::<BankCodeType>();
+    generator.subschema_for::<BankRelationshipStatus>();
+    generator.subschema_for::<CreateBankRequest>();
+    generator.subschema_for::<BankRelationship>();
+    generator.subschema_for::<TransferType>();
+    generator.subschema_for::<TransferDirection>();
+    generator.subschema_for::<TransferTiming>();
+    generator.subschema_for::<FeePaymentMethod>();
+    generator.subschema_for::<TransferStatus>();
+    generator.subschema_for::<CreateTransferRequest>();
+    generator.subschema_for::<Transfer>();
+    generator.subschema_for::<TransferStatusEvent>();
+
+    generator.subschema_for::<JournalEntryType>();
+    generator.subschema_for::<JournalStatus>();
+    generator.subschema_for::<JournalRequest>();
+    generator.subschema_for::<BatchJournalEntry>();
+    generator.subschema_for::<BatchJournalRequest>();
+    generator.subschema_for::<ReverseBatchJournalEntry>();
+    generator.subschema_for::<ReverseBatchJournalRequest>();
+    generator.subschema_for::<Journal>();
+
+    generator.subschema_for::<ErrorMessage>();
+    generator.subschema_for::<ApiError>();
+    generator.subschema_for::<AlpacaError>();
+
+    generator.subschema_for::<CryptoBar>();
+    generator.subschema_for::<LatestCryptoBars>();
+    generator.subschema_for::<Trade>();
+    generator.subschema_for::<LatestTrades>();
+    generator.subschema_for::<Bar>();
+    generator.subschema_for::<Bars>();
+
+    generator.subschema_for::<AssetClass>();
+    generator.subschema_for::<OrderSide>();
+    generator.subschema_for::<OrderType>();
+    generator.subschema_for::<TimeInForce>();
+    generator.subschema_for::<OrderClass>();
+    generator.subschema_for::<PositionIntent>();
+    generator.subschema_for::<TakeProfit>();
+    generator.subschema_for::<StopLoss>();
+    generator.subschema_for::<OrderLegRequest>();
+    generator.subschema_for::<OrderRequest>();
+    generator.subschema_for::<ReplaceOrderRequest>();
+    generator.subschema_for::<OrderResponse>();
+    generator.subschema_for::<OrderResult>();
+    generator.subschema_for::<OrderStatus>();
+    generator.subschema_for::<OrderEvent>();
+    generator.subschema_for::<TradeUpdate>();
+    generator.subschema_for::<OrderQueryStatus>();
+    generator.subschema_for::<SortDirection>();
+    generator.subschema_for::<ListOrdersQuery>();
+    generator.subschema_for::<CancelAllOrdersResponse>();
+
+    generator.subschema_for::<PositionSide>();
+    generator.subschema_for::<OpenPosition>();
+    generator.subschema_for::<ClosePositionRequest>();
+    generator.subschema_for::<CloseAllPositionsRequest>();
+    generator.subschema_for::<CloseAllPositionsResponse>();
+
+    generator.subschema_for::<PortfolioHistory>();
+    generator.subschema_for::<Timeframe>();
+    generator.subschema_for::<HistoryFrame>();
+    generator.subschema_for::<Period>();
+    generator.subschema_for::<IntradayReporting>();
+    generator.subschema_for::<PnlReset>();
+    generator.subschema_for::<PortfolioHistoryQuery>();
+}
+
+/// a single draft 2020-12 document with the schema of every type under `$defs`,
+/// keyed by type name, e.g. `#/$defs/OrderResponse`.
+pub fn bundle() -> Schema {
+    let mut generator = SchemaSettings::draft2020_12().into_generator();
+    add_definitions(&mut generator);
+    let definitions = generator.take_definitions(true);
+
+    json_schema!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "alpaca_types",
+        "$defs": definitions
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::de::DeserializeOwned;
+    use serde_json::{json, Value};
+
+    use super::bundle;
+    use crate::broker::funding::Transfer;
+    use crate::broker::journals::Journal;
+    use crate::orders::OrderResponse;
+    use crate::positions::OpenPosition;
+    use crate::round_trip::{JOURNAL, OPEN_POSITION, ORDER_RESPONSE, TRANSFER};
+
+    /// checks that `fixture` both parses as `T` and validates against the bundled schema
+    /// of `name`, so the schemas accept whatever the serde adapters accept.
+    fn assert_fixture_matches<T: DeserializeOwned>(name: &str, fixture: &Value) {
+        serde_json::from_value::<T>(fixture.clone())
+            .unwrap_or_else(|why| panic!("{name} fixture does not parse: {why}"));
+
+        let mut schema = bundle().to_value();
+        schema["$ref"] = json!(format!("#/$defs/{name}"));
+        let validator = jsonschema::validator_for(&schema)
+            .unwrap_or_else(|why| panic!("invalid schema for {name}: {why}"));
+        let errors: Vec<_> = validator
+            .iter_errors(fixture)
+            .map(|error| format!("{} at {}", error, error.instance_path()))
+            .collect();
+        assert!(
+            errors.is_empty(),
+            "{name} fixture fails its schema: {errors:#?}"
+        );
+    }
+
+    #[test]
+    fn bundle_has_every_type() {
+        let bundle = bundle().to_value();
+        let definitions = bundle["$defs"].as_object().expect("bundle without $defs");
+        for name in [
+            "OrderResponse",
+            "OrderRequest",
+            "TradeUpdate",
+            "OpenPosition",
+            "CryptoBar",
+            "Bars",
+            "PortfolioHistory",
+            "PortfolioHistoryQuery",
+            "ErrorMessage",
+        ] {
+            assert!(definitions.contains_key(name), "missing {name}");
+        }
+
+        // every reference points into the bundle.
+        let bundle_text = bundle.to_string();
+        for reference in bundle_text.split("\"$ref\":\"#/$defs/").skip(1) {
+            let name = &reference[..reference.find('"').expect("unterminated $ref")];
+            assert!(definitions.contains_key(name), "dangling $ref to {name}");
+        }
+    }
+
+    #[test]
+    fn schemas_follow_serde_adapters() {
+        let bundle = bundle().to_value();
+        let properties = |name: &str| -> Value { bundle["$defs"][name]["properties"].clone() };
+
+        let order = properties("OrderResponse");
+        assert_eq!(
+            order["created_at"],
+            json!({"type": "string", "format": "date-time"})
+        );
+        assert_eq!(
+            order["filled_at"]["type"],
+            json!(["string", "null"]),
+            "{order}"
+        );
+        assert_eq!(order["qty"]["type"], json!(["string", "null"]), "{order}");
+
+        let position = properties("OpenPosition");
+        assert_eq!(position["market_value"]["type"], "string");
+        assert!(position["market_value"]["pattern"].is_string());
+
+        let error = properties("ErrorMessage");
+        assert_eq!(error["code"]["type"], "integer");
+
+        assert_eq!(bundle["$defs"]["Period"]["type"], "string");
+    }
+
+    #[test]
+    fn fixtures_match_schemas() {
+        let fixture =
+            |json: &str| -> Value { serde_json::from_str(json).expect("invalid fixture") };
+        assert_fixture_matches::<OrderResponse>("OrderResponse", &fixture(ORDER_RESPONSE));
+        assert_fixture_matches::<OpenPosition>("OpenPosition", &fixture(OPEN_POSITION));
+        assert_fixture_matches::<Transfer>("Transfer", &fixture(TRANSFER));
+        assert_fixture_matches::<Journal>("Journal", &fixture(JOURNAL));
+
+        // Alpaca sends an empty intraday change before the open and may use exponents.
+        let mut position = fixture(OPEN_POSITION);
+        position["unrealized_intraday_plpc"] = json!("");
+        position["unrealized_plpc"] = json!("1e-5");
+        assert_fixture_matches::<OpenPosition>("OpenPosition", &position);
+    }
+}
@@ -0,0 +1,243 @@
+use std::str::FromStr;
+
+use time::OffsetDateTime;
+
+use crate::market_data::PriceBar;
+use crate::trading::portfolio_analytics::{mean, ratio, std_dev, AnalyticsError};
+use crate::trading::portfolio_history::{PortfolioHistory, Timeframe};
+use crate::trading::resample::{join, GapFill};
+
+/// # PeriodReturns
+/// the return of the portfolio and of the benchmark over the same period,
+/// stamped with the end of the period.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PeriodReturns {
+    pub time: OffsetDateTime,
+    pub portfolio: f64,
+    pub benchmark: f64,
+}
+
+/// # BenchmarkComparison
+/// how a portfolio performed relative to a benchmark, e.g. SPY.
+/// Statistics are `None` when they are undefined, e.g. beta against a flat benchmark.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchmarkComparison {
+    pub returns: Vec<PeriodReturns>,
+    /// the annualized return not explained by the benchmark (Jensen's alpha).
+    pub alpha: Option<f64>,
+    pub beta: Option<f64>,
+    pub correlation: Option<f64>,
+    /// the annualized standard deviation of the portfolio's excess returns over the benchmark.
+    pub tracking_error: f64,
+}
+
+/// the sample covariance.
+fn covariance(a: &[f64], b: &[f64]) -> f64 {
+    if a.len() < 2 {
+        return 0.0;
+    }
+    let (mean_a, mean_b) = (mean(a), mean(b));
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| (a - mean_a) * (b - mean_b))
+        .sum::<f64>()
+        / (a.len() - 1) as f64
+}
+
+impl PortfolioHistory {
+    /// compares the history's returns with the closes of a benchmark's `bars`,
+    /// annualized with the history's `timeframe`. Like `analytics`, the portfolio's returns
+    /// come from its P&L, so deposits and withdrawals do not count.
+    /// Each point of the history is joined with the last bar stamped since the previous one,
+    /// see [`align`](crate::trading::resample::align), so the bars must have the history's
+    /// timeframe, e.g. daily bars for a `1D` history, but may be stamped on another convention.
+    /// * `risk_free_rate`: the annual risk free rate, e.g. `0.05` for 5%.
+    pub fn compare_to_benchmark<B: PriceBar>(
+        &self,
+        bars: &[B],
+        risk_free_rate: f64,
+    ) -> Result<BenchmarkComparison, AnalyticsError> {
+        let timeframe = Timeframe::from_str(&self.timeframe)
+            .map_err(|_| AnalyticsError::UnknownTimeframe(self.timeframe.clone()))?;
+        self.compare_to_benchmark_with_periods(bars, risk_free_rate, timeframe.periods_per_year())
+    }
+
+    /// like `compare_to_benchmark`, with an explicit number of periods per year.
+    pub fn compare_to_benchmark_with_periods<B: PriceBar>(
+        &self,
+        bars: &[B],
+        risk_free_rate: f64,
+        periods_per_year: f64,
+    ) -> Result<BenchmarkComparison, AnalyticsError> {
+        // the growth leaves out deposits and withdrawals, which are not returns.
+        let growth = self
+            .growth()?
+            .into_iter()
+            .map(|(time, value)| (time, Some(value)))
+            .collect();
+
+        let points = join(growth, bars, GapFill::Drop);
+        let returns: Vec<PeriodReturns> = points
+            .windows(2)
+            .filter_map(|window| {
+                let (start, end) = (&window[0], &window[1]);
+                let (start_growth, start_close) = (start.equity?, start.close?);
+                if start_growth <= 0.0 || start_close <= 0.0 {
+                    return None;
+                }
+                Some(PeriodReturns {
+                    time: end.time,
+                    portfolio: end.equity? / start_growth - 1.0,
+                    benchmark: end.close? / start_close - 1.0,
+                })
+            })
+            .collect();
+        if returns.len() < 2 {
+            return Err(AnalyticsError::NotEnoughData);
+        }
+
+        let portfolio: Vec<f64> = returns.iter().map(|period| period.portfolio).collect();
+        let benchmark: Vec<f64> = returns.iter().map(|period| period.benchmark).collect();
+        let excess: Vec<f64> = returns
+            .iter()
+            .map(|period| period.portfolio - period.benchmark)
+            .collect();
+
+        let covariance = covariance(&portfolio, &benchmark);
+        let benchmark_volatility = std_dev(&benchmark);
+        let beta = ratio(covariance, benchmark_volatility.powi(2));
+        let correlation = ratio(covariance, std_dev(&portfolio) * benchmark_volatility);
+
+        let risk_free_per_period = risk_free_rate / periods_per_year;
+        let alpha = beta.map(|beta| {
+            let portfolio_excess = mean(&portfolio) - risk_free_per_period;
+            let benchmark_excess = mean(&benchmark) - risk_free_per_period;
+            (portfolio_excess - beta * benchmark_excess) * periods_per_year
+        });
+
+        Ok(BenchmarkComparison {
+            returns,
+            alpha,
+            beta,
+            correlation,
+            tracking_error: std_dev(&excess) * periods_per_year.sqrt(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::market_data::stock::Bars;
+    use crate::trading::portfolio_analytics::AnalyticsError;
+    use crate::trading::portfolio_history::PortfolioHistory;
+
+    // a week of daily equity, with the benchmark missing the bar of 2024-07-27.
+    const HISTORY: &str = r#"
+    {
+        "timestamp": [1721793600, 1721880000, 1721966400, 1722052800, 1722139200, 1722225600, 1722312000],
+        "equity": [100000.0, 99610.5, 100580.25, 100890.0, 100301.75, 101240.5, 101455.0],
+        "profit_loss": [0, -389.5, 969.75, 309.75, -588.25, 938.75, 214.5],
+        "profit_loss_pct": [0, -0.003895, 0.009735, 0.00308, -0.005831, 0.009359, 0.002119],
+        "base_value": 100000.0,
+        "base_value_asof": "2024-07-23",
+        "timeframe": "1D"
+    }
+    "#;
+
+    const SPY: &str = r#"
+    {
+        "bars": {
+            "SPY": [
+                {"t": "2024-07-24T04:00:00Z", "o": 541.0, "h": 542.0, "l": 540.0, "c": 541.23, "v": 100, "n": 10, "vw": 541.0},
+                {"t": "2024-07-25T04:00:00Z", "o": 541.0, "h": 542.0, "l": 538.0, "c": 538.41, "v": 100, "n": 10, "vw": 540.0},
+                {"t": "2024-07-26T04:00:00Z", "o": 539.0, "h": 545.0, "l": 538.0, "c": 544.44, "v": 100, "n": 10, "vw": 542.0},
+                {"t": "2024-07-28T04:00:00Z", "o": 545.0, "h": 546.0, "l": 542.0, "c": 543.01, "v": 100, "n": 10, "vw": 544.0},
+                {"t": "2024-07-29T04:00:00Z", "o": 543.0, "h": 549.0, "l": 543.0, "c": 548.99, "v": 100, "n": 10, "vw": 547.0},
+                {"t": "2024-07-30T04:00:00Z", "o": 549.0, "h": 551.0, "l": 548.0, "c": 550.12, "v": 100, "n": 10, "vw": 550.0}
+            ]
+        },
+        "next_page_token": null
+    }
+    "#;
+
+    fn assert_close(got: Option<f64>, wanted: f64) {
+        let got = got.expect("statistic is undefined");
+        assert!((got - wanted).abs() < 1e-9, "got {got}, wanted {wanted}");
+    }
+
+    #[test]
+    fn compares_to_benchmark() {
+        let history = serde_json::from_str::<PortfolioHistory>(HISTORY)
+            .expect("failed to parse portfolio history");
+        let bars = serde_json::from_str::<Bars>(SPY).expect("failed to parse bars");
+
+        let comparison = history
+            .compare_to_benchmark(&bars.bars["SPY"], 0.0)
+            .expect("failed to compare");
+
+        // the return over the missing bar spans two days.
+        assert_eq!(comparison.returns.len(), 5);
+        assert_close(comparison.beta, 0.8494703071120258);
+        assert_close(comparison.correlation, 0.9979427520037554);
+        assert_close(comparison.alpha, 0.029709714450746535);
+        assert_close(Some(comparison.tracking_error), 0.019315026608094445);
+    }
+
+    #[test]
+    fn leaves_out_deposits() {
+        let mut history = serde_json::from_str::<PortfolioHistory>(HISTORY)
+            .expect("failed to parse portfolio history");
+        let bars = serde_json::from_str::<Bars>(SPY).expect("failed to parse bars");
+
+        // a deposit of 50000 on 2024-07-27 moves equity but not the P&L.
+        for equity in history.equity.iter_mut().skip(3).flatten() {
+            *equity += 50_000.0;
+        }
+        history.cashflow.insert(
+            "CSD".to_string(),
+            vec![None, None, None, Some(50_000.0), None, None, None],
+        );
+
+        let comparison = history
+            .compare_to_benchmark(&bars.bars["SPY"], 0.0)
+            .expect("failed to compare");
+
+        // the period over the missing bar compounds the P&L of 2024-07-27 and 2024-07-28.
+        let wanted = (1.0 + 309.75 / 100_580.25) * (1.0 - 588.25 / 150_890.0) - 1.0;
+        assert_close(Some(comparison.returns[2].portfolio), wanted);
+        assert_close(Some(comparison.returns[3].portfolio), 938.75 / 150_301.75);
+    }
+
+    #[test]
+    fn joins_bars_stamped_on_another_convention() {
+        let history = serde_json::from_str::<PortfolioHistory>(HISTORY)
+            .expect("failed to parse portfolio history");
+        let bars = serde_json::from_str::<Bars>(SPY).expect("failed to parse bars");
+
+        // the same bars stamped at UTC midnight instead of New York midnight.
+        let mut shifted = bars.bars["SPY"].clone();
+        for bar in &mut shifted {
+            bar.timestamp -= time::Duration::hours(4);
+        }
+
+        let wanted = history
+            .compare_to_benchmark(&bars.bars["SPY"], 0.0)
+            .expect("failed to compare");
+        let got = history
+            .compare_to_benchmark(&shifted, 0.0)
+            .expect("failed to compare");
+        assert_eq!(got, wanted);
+    }
+
+    #[test]
+    fn needs_overlapping_points() {
+        let history = serde_json::from_str::<PortfolioHistory>(HISTORY)
+            .expect("failed to parse portfolio history");
+        let bars = serde_json::from_str::<Bars>(SPY).expect("failed to parse bars");
+
+        assert_eq!(
+            history.compare_to_benchmark(&bars.bars["SPY"][..2], 0.0),
+            Err(AnalyticsError::NotEnoughData)
+        );
+    }
+}
@@ -0,0 +1,259 @@
+use std::collections::BTreeSet;
+use std::fmt::Display;
+use std::io::Write;
+
+use crate::trading::portfolio_history::HistoryFrame;
+
+/// the columns every export starts with, followed by one `cashflow_<TYPE>` column
+/// per cashflow activity type found in the frames.
+const COLUMNS: [&str; 6] = [
+    "time",
+    "equity",
+    "profit_loss",
+    "profit_loss_percent",
+    "base_value",
+    "base_value_as_of",
+];
+
+/// # ExportError
+/// describes why a frame series could not be exported.
+#[derive(Debug)]
+pub enum ExportError {
+    #[cfg(feature = "csv")]
+    Csv(csv::Error),
+    /// a frame time could not be formatted as RFC 3339.
+    Time(time::error::Format),
+    #[cfg(feature = "arrow")]
+    Arrow(arrow_schema::ArrowError),
+    #[cfg(feature = "arrow")]
+    Parquet(parquet::errors::ParquetError),
+}
+
+impl Display for ExportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            #[cfg(feature = "csv")]
+            ExportError::Csv(why) => write!(f, "failed to write csv: {why}"),
+            ExportError::Time(why) => write!(f, "failed to format time: {why}"),
+            #[cfg(feature = "arrow")]
+            ExportError::Arrow(why) => write!(f, "failed to build arrow batch: {why}"),
+            #[cfg(feature = "arrow")]
+            ExportError::Parquet(why) => write!(f, "failed to write parquet: {why}"),
+        }
+    }
+}
+
+impl std::error::Error for ExportError {}
+
+#[cfg(feature = "csv")]
+impl From<csv::Error> for ExportError {
+    fn from(why: csv::Error) -> Self {
+        ExportError::Csv(why)
+    }
+}
+
+impl From<time::error::Format> for ExportError {
+    fn from(why: time::error::Format) -> Self {
+        ExportError::Time(why)
+    }
+}
+
+#[cfg(feature = "arrow")]
+impl From<arrow_schema::ArrowError> for ExportError {
+    fn from(why: arrow_schema::ArrowError) -> Self {
+        ExportError::Arrow(why)
+    }
+}
+
+#[cfg(feature = "arrow")]
+impl From<parquet::errors::ParquetError> for ExportError {
+    fn from(why: parquet::errors::ParquetError) -> Self {
+        ExportError::Parquet(why)
+    }
+}
+
+/// the cashflow activity types of all frames, sorted so columns are stable.
+fn cashflow_types(frames: &[HistoryFrame]) -> Vec<&str> {
+    frames
+        .iter()
+        .flat_map(|frame| frame.cashflow.keys().map(String::as_str))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// formats an optional number, leaving missing points as empty cells.
+#[cfg(feature = "csv")]
+fn cell(value: Option<f64>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+/// writes frames as csv with a header row. Missing points are empty cells.
+#[cfg(feature = "csv")]
+pub fn write_csv<W: Write>(frames: &[HistoryFrame], writer: W) -> Result<(), ExportError> {
+    use time::format_description::well_known::Rfc3339;
+
+    let kinds = cashflow_types(frames);
+    let mut writer = csv::Writer::from_writer(writer);
+
+    let header = COLUMNS
+        .iter()
+        .map(|column| column.to_string())
+        .chain(kinds.iter().map(|kind| format!("cashflow_{kind}")));
+    writer.write_record(header)?;
+
+    for frame in frames {
+        let mut record = vec![
+            frame.time.format(&Rfc3339)?,
+            cell(frame.equity),
+            cell(frame.profit_loss),
+            cell(frame.profit_loss_percent),
+            frame.base_value.to_string(),
+            frame.base_value_as_of.to_string(),
+        ];
+        record.extend(
+            kinds
+                .iter()
+                .map(|kind| cell(frame.cashflow.get(*kind).copied())),
+        );
+        writer.write_record(&record)?;
+    }
+
+    writer.flush().map_err(csv::Error::from)?;
+    Ok(())
+}
+
+/// converts frames into an arrow record batch, with `time` as a UTC nanosecond timestamp.
+#[cfg(feature = "arrow")]
+pub fn to_record_batch(frames: &[HistoryFrame]) -> Result<arrow_array::RecordBatch, ExportError> {
+    use std::sync::Arc;
+
+    use arrow_array::{ArrayRef, Float64Array, RecordBatch, StringArray, TimestampNanosecondArray};
+    use arrow_schema::{DataType, Field, Schema, TimeUnit};
+
+    let kinds = cashflow_types(frames);
+    let time_type = DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into()));
+    let mut fields = vec![
+        Field::new(COLUMNS[0], time_type, false),
+        Field::new(COLUMNS[1], DataType::Float64, true),
+        Field::new(COLUMNS[2], DataType::Float64, true),
+        Field::new(COLUMNS[3], DataType::Float64, true),
+        Field::new(COLUMNS[4], DataType::Float64, false),
+        Field::new(COLUMNS[5], DataType::Utf8, false),
+    ];
+    fields.extend(
+        kinds
+            .iter()
+            .map(|kind| Field::new(format!("cashflow_{kind}"), DataType::Float64, true)),
+    );
+
+    // nanoseconds only overflow an i64 after the year 2262.
+    let times = frames
+        .iter()
+        .map(|frame| i64::try_from(frame.time.unix_timestamp_nanos()).ok());
+
+    let mut columns: Vec<ArrayRef> = vec![
+        Arc::new(TimestampNanosecondArray::from_iter(times).with_timezone("UTC")),
+        Arc::new(Float64Array::from_iter(
+            frames.iter().map(|frame| frame.equity),
+        )),
+        Arc::new(Float64Array::from_iter(
+            frames.iter().map(|frame| frame.profit_loss),
+        )),
+        Arc::new(Float64Array::from_iter(
+            frames.iter().map(|frame| frame.profit_loss_percent),
+        )),
+        Arc::new(Float64Array::from_iter_values(
+            frames.iter().map(|frame| frame.base_value),
+        )),
+        Arc::new(StringArray::from_iter_values(
+            frames.iter().map(|frame| &*frame.base_value_as_of),
+        )),
+    ];
+    columns.extend(kinds.iter().map(|kind| {
+        let points = frames
+            .iter()
+            .map(|frame| frame.cashflow.get(*kind).copied());
+        Arc::new(Float64Array::from_iter(points)) as ArrayRef
+    }));
+
+    Ok(RecordBatch::try_new(
+        Arc::new(Schema::new(fields)),
+        columns,
+    )?)
+}
+
+/// writes frames as a single parquet row group.
+#[cfg(feature = "arrow")]
+pub fn write_parquet<W: Write + Send>(
+    frames: &[HistoryFrame],
+    writer: W,
+) -> Result<(), ExportError> {
+    let batch = to_record_batch(frames)?;
+    let mut writer = parquet::arrow::ArrowWriter::try_new(writer, batch.schema(), None)?;
+    writer.write(&batch)?;
+    writer.close()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use time::macros::datetime;
+
+    use crate::trading::portfolio_history::HistoryFrame;
+
+    fn frames() -> Vec<HistoryFrame> {
+        let base_value_as_of = "2024-07-24".into();
+        vec![
+            HistoryFrame {
+                time: datetime!(2024-07-24 20:00 UTC),
+                equity: None,
+                profit_loss: None,
+                profit_loss_percent: None,
+                base_value: 100.0,
+                base_value_as_of: std::sync::Arc::clone(&base_value_as_of),
+                cashflow: HashMap::new(),
+            },
+            HistoryFrame {
+                time: datetime!(2024-07-25 20:00 UTC),
+                equity: Some(101.5),
+                profit_loss: Some(1.5),
+                profit_loss_percent: Some(0.015),
+                base_value: 100.0,
+                base_value_as_of,
+                cashflow: HashMap::from([("DIV".to_string(), 12.5)]),
+            },
+        ]
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn writes_csv() {
+        let mut out = vec![];
+        super::write_csv(&frames(), &mut out).expect("failed to write csv");
+
+        let wanted = "\
+time,equity,profit_loss,profit_loss_percent,base_value,base_value_as_of,cashflow_DIV
+2024-07-24T20:00:00Z,,,,100,2024-07-24,
+2024-07-25T20:00:00Z,101.5,1.5,0.015,100,2024-07-24,12.5
+";
+        assert_eq!(String::from_utf8(out).expect("csv is not utf8"), wanted);
+    }
+
+    #[cfg(feature = "arrow")]
+    #[test]
+    fn writes_arrow_and_parquet() {
+        use arrow_array::Array;
+
+        let batch = super::to_record_batch(&frames()).expect("failed to build batch");
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.num_columns(), 7);
+        assert_eq!(batch.column(1).null_count(), 1);
+
+        let mut out = vec![];
+        super::write_parquet(&frames(), &mut out).expect("failed to write parquet");
+        assert!(out.starts_with(b"PAR1"));
+    }
+}
@@ -1 +1,7 @@
+pub mod benchmark;
+#[cfg(any(feature = "csv", feature = "arrow"))]
+pub mod history_export;
+pub mod portfolio_analytics;
 pub mod portfolio_history;
+pub mod portfolio_history_query;
+pub mod resample;
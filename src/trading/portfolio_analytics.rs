@@ -0,0 +1,338 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use time::OffsetDateTime;
+
+use crate::trading::portfolio_history::{PortfolioHistory, Timeframe};
+
+/// # AnalyticsError
+/// describes why statistics could not be computed for a portfolio history.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnalyticsError {
+    /// the timeframe is not one Alpaca documents, so it cannot be annualized.
+    UnknownTimeframe(String),
+    /// a timestamp is outside of the range `OffsetDateTime` supports.
+    InvalidTimestamp(i64),
+    /// fewer than two equity points, so there are no returns.
+    NotEnoughData,
+}
+
+impl Display for AnalyticsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnalyticsError::UnknownTimeframe(timeframe) => {
+                write!(f, "cannot annualize unknown timeframe {timeframe}")
+            }
+            AnalyticsError::InvalidTimestamp(timestamp) => {
+                write!(f, "invalid timestamp {timestamp}")
+            }
+            AnalyticsError::NotEnoughData => write!(f, "at least two equity points are needed"),
+        }
+    }
+}
+
+impl std::error::Error for AnalyticsError {}
+
+/// # Drawdown
+/// the largest fall of equity from a previous peak.
+/// * `depth`: the fall as a negative fraction of the peak, e.g. `-0.1` for 10%.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Drawdown {
+    pub depth: f64,
+    pub peak: OffsetDateTime,
+    pub trough: OffsetDateTime,
+}
+
+/// # PortfolioAnalytics
+/// performance statistics of a portfolio history. Returns leave out deposits and withdrawals,
+/// see `PortfolioHistory::growth`. Ratios are `None` when they are undefined,
+/// e.g. the Sharpe ratio of a flat equity curve.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PortfolioAnalytics {
+    /// the return of each period, stamped with the end of the period.
+    pub returns: Vec<(OffsetDateTime, f64)>,
+    pub cumulative_return: f64,
+    pub max_drawdown: Option<Drawdown>,
+    pub annualized_volatility: f64,
+    pub sharpe_ratio: Option<f64>,
+    pub sortino_ratio: Option<f64>,
+    /// the share of periods with a positive return, out of the periods where equity moved.
+    pub win_rate: Option<f64>,
+}
+
+pub(crate) fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+/// the sample standard deviation.
+pub(crate) fn std_dev(values: &[f64]) -> f64 {
+    if values.len() < 2 {
+        return 0.0;
+    }
+    let avg = mean(values);
+    let variance = values
+        .iter()
+        .map(|value| (value - avg).powi(2))
+        .sum::<f64>()
+        / (values.len() - 1) as f64;
+    variance.sqrt()
+}
+
+/// divides, returning `None` for an empty denominator.
+pub(crate) fn ratio(numerator: f64, denominator: f64) -> Option<f64> {
+    (denominator > f64::EPSILON).then(|| numerator / denominator)
+}
+
+impl PortfolioHistory {
+    /// the value of 1 invested at the first point with equity, stamped with each point.
+    /// It grows by each period's `profit_loss` over the previous equity, so deposits and
+    /// withdrawals do not count as gains or losses. A point without P&L falls back to the
+    /// change in equity less its `cashflow`.
+    /// Intervals without equity (e.g. market holidays) are left out, as are periods starting
+    /// from no equity, which have no meaningful return.
+    pub(crate) fn growth(&self) -> Result<Vec<(OffsetDateTime, f64)>, AnalyticsError> {
+        let mut growth: Vec<(OffsetDateTime, f64)> = Vec::with_capacity(self.timestamp.len());
+        let mut previous: Option<f64> = None;
+        for (idx, (timestamp, equity)) in self.timestamp.iter().zip(&self.equity).enumerate() {
+            let Some(equity) = *equity else {
+                continue;
+            };
+            let time = OffsetDateTime::from_unix_timestamp(*timestamp)
+                .map_err(|_| AnalyticsError::InvalidTimestamp(*timestamp))?;
+
+            match (previous, growth.last()) {
+                (Some(start), Some((_, value))) if start > 0.0 => {
+                    let profit_loss = match self.profit_loss.get(idx).copied().flatten() {
+                        Some(profit_loss) => profit_loss,
+                        None => {
+                            let cashflow: f64 = self
+                                .cashflow
+                                .values()
+                                .filter_map(|points| points.get(idx).copied().flatten())
+                                .sum();
+                            equity - start - cashflow
+                        }
+                    };
+                    growth.push((time, value * (1.0 + profit_loss / start)));
+                }
+                (_, None) if equity > 0.0 => growth.push((time, 1.0)),
+                _ => {}
+            }
+            previous = Some(equity);
+        }
+        Ok(growth)
+    }
+
+    /// computes performance statistics, annualized with the history's `timeframe`.
+    /// * `risk_free_rate`: the annual risk free rate, e.g. `0.05` for 5%.
+    pub fn analytics(&self, risk_free_rate: f64) -> Result<PortfolioAnalytics, AnalyticsError> {
+        let timeframe = Timeframe::from_str(&self.timeframe)
+            .map_err(|_| AnalyticsError::UnknownTimeframe(self.timeframe.clone()))?;
+        self.analytics_with_periods(risk_free_rate, timeframe.periods_per_year())
+    }
+
+    /// like `analytics`, with an explicit number of periods per year.
+    /// Useful for accounts trading around the clock, e.g. crypto.
+    pub fn analytics_with_periods(
+        &self,
+        risk_free_rate: f64,
+        periods_per_year: f64,
+    ) -> Result<PortfolioAnalytics, AnalyticsError> {
+        let points = self.growth()?;
+        if points.len() < 2 {
+            return Err(AnalyticsError::NotEnoughData);
+        }
+
+        let returns: Vec<(OffsetDateTime, f64)> = points
+            .windows(2)
+            .map(|window| (window[1].0, window[1].1 / window[0].1 - 1.0))
+            .collect();
+        let values: Vec<f64> = returns.iter().map(|(_, value)| *value).collect();
+        let cumulative_return = points[points.len() - 1].1 / points[0].1 - 1.0;
+
+        let mut max_drawdown: Option<Drawdown> = None;
+        let mut peak = points[0];
+        for point in &points {
+            if point.1 > peak.1 {
+                peak = *point;
+            }
+            if peak.1 <= 0.0 {
+                continue;
+            }
+            let depth = point.1 / peak.1 - 1.0;
+            if depth < max_drawdown.as_ref().map_or(0.0, |drawdown| drawdown.depth) {
+                max_drawdown = Some(Drawdown {
+                    depth,
+                    peak: peak.0,
+                    trough: point.0,
+                });
+            }
+        }
+
+        let annualization = periods_per_year.sqrt();
+        let risk_free_per_period = risk_free_rate / periods_per_year;
+        let (sharpe_ratio, sortino_ratio, annualized_volatility) = if values.is_empty() {
+            (None, None, 0.0)
+        } else {
+            let excess = mean(&values) - risk_free_per_period;
+            let volatility = std_dev(&values);
+            let downside = (values
+                .iter()
+                .map(|value| (value - risk_free_per_period).min(0.0).powi(2))
+                .sum::<f64>()
+                / values.len() as f64)
+                .sqrt();
+
+            (
+                ratio(excess, volatility).map(|sharpe| sharpe * annualization),
+                ratio(excess, downside).map(|sortino| sortino * annualization),
+                volatility * annualization,
+            )
+        };
+
+        let moved = values.iter().filter(|value| **value != 0.0).count();
+        let wins = values.iter().filter(|value| **value > 0.0).count();
+        let win_rate = (moved > 0).then(|| wins as f64 / moved as f64);
+
+        Ok(PortfolioAnalytics {
+            returns,
+            cumulative_return,
+            max_drawdown,
+            annualized_volatility,
+            sharpe_ratio,
+            sortino_ratio,
+            win_rate,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::AnalyticsError;
+    use crate::trading::portfolio_history::PortfolioHistory;
+
+    fn history(equity: Vec<f64>, timeframe: &str) -> PortfolioHistory {
+        let start = datetime!(2024-07-22 20:00 UTC).unix_timestamp();
+        PortfolioHistory {
+            timestamp: (0..equity.len() as i64)
+                .map(|day| start + day * 86_400)
+                .collect(),
+            profit_loss: std::iter::once(Some(0.0))
+                .chain(equity.windows(2).map(|pair| Some(pair[1] - pair[0])))
+                .collect(),
+            profit_loss_pct: vec![Some(0.0); equity.len()],
+            equity: equity.into_iter().map(Some).collect(),
+            base_value: 100.0,
+            base_value_asof: "2024-07-19".to_string(),
+            timeframe: timeframe.to_string(),
+            cashflow: Default::default(),
+            #[cfg(feature = "extra_fields")]
+            extra: Default::default(),
+        }
+    }
+
+    fn assert_close(got: f64, wanted: f64) {
+        assert!((got - wanted).abs() < 1e-9, "got {got}, wanted {wanted}");
+    }
+
+    #[test]
+    fn computes_returns_and_drawdown() {
+        let analytics = history(vec![100.0, 110.0, 99.0, 104.5, 121.0], "1D")
+            .analytics(0.0)
+            .expect("failed to compute analytics");
+
+        let returns: Vec<f64> = analytics.returns.iter().map(|(_, r)| *r).collect();
+        assert_eq!(returns.len(), 4);
+        assert_close(returns[0], 0.1);
+        assert_close(returns[1], -0.1);
+        assert_close(analytics.cumulative_return, 0.21);
+        assert_eq!(analytics.win_rate, Some(0.75));
+
+        let drawdown = analytics.max_drawdown.expect("missing drawdown");
+        assert_close(drawdown.depth, -0.1);
+        assert_eq!(drawdown.peak, datetime!(2024-07-23 20:00 UTC));
+        assert_eq!(drawdown.trough, datetime!(2024-07-24 20:00 UTC));
+    }
+
+    #[test]
+    fn annualizes_with_timeframe() {
+        let daily = history(vec![100.0, 101.0, 100.0, 102.0, 101.0], "1D")
+            .analytics(0.02)
+            .expect("failed to compute analytics");
+        let hourly = history(vec![100.0, 101.0, 100.0, 102.0, 101.0], "1H")
+            .analytics(0.02)
+            .expect("failed to compute analytics");
+
+        assert_close(
+            hourly.annualized_volatility / daily.annualized_volatility,
+            6.5_f64.sqrt(),
+        );
+        assert!(daily.sharpe_ratio.is_some());
+        assert!(daily.sortino_ratio.is_some());
+    }
+
+    #[test]
+    fn flat_history_has_no_ratios() {
+        let analytics = history(vec![100.0; 4], "1D")
+            .analytics(0.0)
+            .expect("failed to compute analytics");
+        assert_eq!(analytics.max_drawdown, None);
+        assert_eq!(analytics.sharpe_ratio, None);
+        assert_eq!(analytics.win_rate, None);
+    }
+
+    // a deposit of 1000 on 2024-07-24 and a withdrawal of 500 on 2024-07-26.
+    const CASHFLOW_HISTORY: &str = r#"
+    {
+        "timestamp": [1721678400, 1721764800, 1721851200, 1721937600, 1722024000],
+        "equity": [100.0, 110.0, 1110.0, 1221.0, 710.0],
+        "profit_loss": [0.0, 10.0, 0.0, 111.0, null],
+        "profit_loss_pct": [0.0, 0.1, 0.0, 0.1, null],
+        "base_value": 100.0,
+        "base_value_asof": "2024-07-19",
+        "timeframe": "1D",
+        "cashflow": {
+            "CSD": [null, null, 1000.0, null, null],
+            "CSW": [null, null, null, null, -500.0]
+        }
+    }
+    "#;
+
+    #[test]
+    fn leaves_out_deposits_and_withdrawals() {
+        let history = serde_json::from_str::<PortfolioHistory>(CASHFLOW_HISTORY)
+            .expect("failed to parse portfolio history");
+        let analytics = history.analytics(0.0).expect("failed to compute analytics");
+
+        // the last point has no P&L, so its return is the equity change less the withdrawal.
+        let returns: Vec<f64> = analytics.returns.iter().map(|(_, r)| *r).collect();
+        assert_eq!(returns.len(), 4);
+        assert_close(returns[0], 0.1);
+        assert_close(returns[1], 0.0);
+        assert_close(returns[2], 0.1);
+        assert_close(returns[3], -11.0 / 1221.0);
+        assert_close(
+            analytics.cumulative_return,
+            1.21 * (1.0 - 11.0 / 1221.0) - 1.0,
+        );
+        assert_eq!(analytics.win_rate, Some(2.0 / 3.0));
+
+        let drawdown = analytics.max_drawdown.expect("missing drawdown");
+        assert_close(drawdown.depth, -11.0 / 1221.0);
+        assert_eq!(drawdown.trough, datetime!(2024-07-26 20:00 UTC));
+    }
+
+    #[test]
+    fn rejects_unusable_histories() {
+        assert_eq!(
+            history(vec![100.0, 101.0], "2W").analytics(0.0),
+            Err(AnalyticsError::UnknownTimeframe("2W".to_string()))
+        );
+        assert_eq!(
+            history(vec![100.0], "1D").analytics(0.0),
+            Err(AnalyticsError::NotEnoughData)
+        );
+    }
+}
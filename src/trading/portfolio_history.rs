@@ -1,48 +1,132 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::Arc;
+
 use serde::{Deserialize, Serialize};
+use time::serde::rfc3339;
+use time::OffsetDateTime;
 
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
 #[cfg(feature = "ts")]
 use ts_rs::TS;
 
-#[derive(Deserialize, Default, Debug)]
-#[cfg_attr(feature = "ts", derive(TS), ts(export))]
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
 pub struct PortfolioHistory {
+    #[cfg_attr(feature = "ts", ts(type = "Array<number>"))]
     pub timestamp: Vec<i64>,
-    pub equity: Vec<f64>,
-    pub profit_loss: Vec<f64>,
-    pub profit_loss_pct: Vec<f64>,
+    /// points are `None` for intervals without data, e.g. when the market was closed.
+    pub equity: Vec<Option<f64>>,
+    pub profit_loss: Vec<Option<f64>>,
+    pub profit_loss_pct: Vec<Option<f64>>,
     pub base_value: f64,
     pub base_value_asof: String,
     pub timeframe: String,
+    /// cashflow amounts keyed by activity type (e.g. `DIV`, `FEE`), one entry per timestamp.
+    /// Only present when requested with `cashflow_types`.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    #[cfg_attr(
+        feature = "ts",
+        ts(as = "Option<HashMap<String, Vec<Option<f64>>>>", optional)
+    )]
+    pub cashflow: HashMap<String, Vec<Option<f64>>>,
+    /// fields Alpaca sent that this type does not know yet.
+    #[cfg(feature = "extra_fields")]
+    #[cfg_attr(not(feature = "deny_unknown_fields"), serde(flatten))]
+    #[cfg_attr(feature = "deny_unknown_fields", serde(skip))]
+    #[cfg_attr(feature = "ts", ts(skip))]
+    pub extra: std::collections::BTreeMap<String, serde_json::Value>,
+}
+
+/// # HistoryError
+/// describes why a portfolio history could not be converted into frames.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HistoryError {
+    /// a timestamp is outside of the range `OffsetDateTime` supports.
+    InvalidTimestamp(time::error::ComponentRange),
+    /// a series does not have one point per timestamp.
+    LengthMismatch {
+        field: String,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl Display for HistoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HistoryError::InvalidTimestamp(why) => write!(f, "invalid timestamp: {why}"),
+            HistoryError::LengthMismatch {
+                field,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{field} has {found} points but there are {expected} timestamps"
+            ),
+        }
+    }
 }
+
+impl std::error::Error for HistoryError {}
+
 impl PortfolioHistory {
+    /// errors if any series does not have exactly one point per timestamp.
+    pub fn check_lengths(&self) -> Result<(), HistoryError> {
+        let expected = self.timestamp.len();
+        let series = [
+            ("equity".to_string(), self.equity.len()),
+            ("profit_loss".to_string(), self.profit_loss.len()),
+            ("profit_loss_pct".to_string(), self.profit_loss_pct.len()),
+        ];
+        let cashflow = self
+            .cashflow
+            .iter()
+            .map(|(kind, points)| (format!("cashflow.{kind}"), points.len()));
+
+        for (field, found) in series.into_iter().chain(cashflow) {
+            if found != expected {
+                return Err(HistoryError::LengthMismatch {
+                    field,
+                    expected,
+                    found,
+                });
+            }
+        }
+        Ok(())
+    }
+
     /// converts PortfolioHistory into a Vec of HistoryFrame.
-    /// Errors if the timestamp cannot be parsed.
-    pub fn to_frames(self) -> Result<Vec<HistoryFrame>, time::error::ComponentRange> {
-        let mut collection = vec![];
-        for (idx, timestamp) in self.timestamp.iter().enumerate() {
-            let report_time = time::OffsetDateTime::from_unix_timestamp(*timestamp)?;
+    /// Errors if a timestamp cannot be parsed or the series lengths do not match.
+    pub fn to_frames(self) -> Result<Vec<HistoryFrame>, HistoryError> {
+        self.check_lengths()?;
 
-            let Some(equity) = self.equity.get(idx) else {
-                continue;
-            };
-            let Some(profit_loss) = self.profit_loss.get(idx) else {
-                continue;
-            };
+        // shared by every frame instead of copying the string into each one.
+        let base_value_as_of: Arc<str> = Arc::from(self.base_value_asof);
 
-            let Some(profit_loss_pct) = self.profit_loss_pct.get(idx) else {
-                continue;
-            };
+        let mut collection = Vec::with_capacity(self.timestamp.len());
+        for (idx, timestamp) in self.timestamp.iter().enumerate() {
+            let report_time = OffsetDateTime::from_unix_timestamp(*timestamp)
+                .map_err(HistoryError::InvalidTimestamp)?;
 
-            let base_value = self.base_value;
-            let base_value_as_of = self.base_value_asof.clone();
+            let cashflow = self
+                .cashflow
+                .iter()
+                .filter_map(|(kind, points)| Some((kind.clone(), points[idx]?)))
+                .collect();
 
             let frame = HistoryFrame {
-                base_value,
-                equity: *equity,
-                base_value_as_of,
-                profit_loss: *profit_loss,
-                time: report_time.to_string(),
-                profit_loss_percent: *profit_loss_pct,
+                base_value: self.base_value,
+                equity: self.equity[idx],
+                base_value_as_of: Arc::clone(&base_value_as_of),
+                profit_loss: self.profit_loss[idx],
+                time: report_time,
+                profit_loss_percent: self.profit_loss_pct[idx],
+                cashflow,
             };
             collection.push(frame);
         }
@@ -51,28 +135,109 @@ impl PortfolioHistory {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-#[cfg_attr(feature = "ts", derive(TS), ts(export))]
+/// # Timeframe
+/// The resolution of the points in a portfolio history.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum Timeframe {
+    #[serde(rename = "1Min")]
+    OneMinute,
+    #[serde(rename = "5Min")]
+    FiveMinutes,
+    #[serde(rename = "15Min")]
+    FifteenMinutes,
+    #[serde(rename = "1H")]
+    OneHour,
+    #[serde(rename = "1D")]
+    OneDay,
+}
+
+impl Timeframe {
+    /// the number of points in a year of regular trading hours (252 days of 6.5 hours),
+    /// used to annualize statistics.
+    pub fn periods_per_year(&self) -> f64 {
+        const TRADING_DAYS: f64 = 252.0;
+        const MINUTES_PER_DAY: f64 = 390.0;
+        match self {
+            Timeframe::OneMinute => TRADING_DAYS * MINUTES_PER_DAY,
+            Timeframe::FiveMinutes => TRADING_DAYS * MINUTES_PER_DAY / 5.0,
+            Timeframe::FifteenMinutes => TRADING_DAYS * MINUTES_PER_DAY / 15.0,
+            Timeframe::OneHour => TRADING_DAYS * MINUTES_PER_DAY / 60.0,
+            Timeframe::OneDay => TRADING_DAYS,
+        }
+    }
+
+    /// the length of one point.
+    pub fn duration(&self) -> time::Duration {
+        match self {
+            Timeframe::OneMinute => time::Duration::minutes(1),
+            Timeframe::FiveMinutes => time::Duration::minutes(5),
+            Timeframe::FifteenMinutes => time::Duration::minutes(15),
+            Timeframe::OneHour => time::Duration::HOUR,
+            Timeframe::OneDay => time::Duration::DAY,
+        }
+    }
+}
+
+impl FromStr for Timeframe {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1Min" => Ok(Timeframe::OneMinute),
+            "5Min" => Ok(Timeframe::FiveMinutes),
+            "15Min" => Ok(Timeframe::FifteenMinutes),
+            "1H" => Ok(Timeframe::OneHour),
+            "1D" => Ok(Timeframe::OneDay),
+            other => Err(format!("Unknown timeframe: {other}")),
+        }
+    }
+}
+
+impl Display for Timeframe {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Timeframe::OneMinute => write!(f, "1Min"),
+            Timeframe::FiveMinutes => write!(f, "5Min"),
+            Timeframe::FifteenMinutes => write!(f, "15Min"),
+            Timeframe::OneHour => write!(f, "1H"),
+            Timeframe::OneDay => write!(f, "1D"),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
 ///  An alternative structure to convert Portfolio History into frames.
 pub struct HistoryFrame {
-    // The time of the report
-    pub time: String,
+    // The time of the report, serialized as RFC 3339
+    #[serde(with = "rfc3339")]
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::schema::DateTime"))]
+    pub time: OffsetDateTime,
     // equity value of the account in dollar amount as of the end of each time window
-    pub equity: f64,
+    pub equity: Option<f64>,
     // The Dollar amount of Profit or Loss
-    pub profit_loss: f64,
+    pub profit_loss: Option<f64>,
     // The Profit Loss Percent compared to the basis
-    pub profit_loss_percent: f64,
+    pub profit_loss_percent: Option<f64>,
     // basis in dollar of the profit loss calculation
     pub base_value: f64,
     // If included, then it indicates that the base_value is the account's closing
-    // equity value at this trading date
-    pub base_value_as_of: String,
+    // equity value at this trading date. Shared by all frames of a history.
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    pub base_value_as_of: Arc<str>,
+    // cashflow amounts of this time window keyed by activity type, if requested
+    pub cashflow: HashMap<String, f64>,
 }
 
 #[cfg(test)]
 mod portfolio_history_test {
-    use crate::trading::portfolio_history::PortfolioHistory;
+    use time::macros::datetime;
+
+    use crate::trading::portfolio_history::{HistoryError, PortfolioHistory};
     const JSON_DATA: &str = r#"
         {
             "timestamp": [ 1722000600, 1722004200, 1722007800,1722011400, 1722015000, 1722018600, 1722022200],
@@ -99,4 +264,55 @@ mod portfolio_history_test {
         let frames_result = res.to_frames();
         assert!(frames_result.is_ok());
     }
+
+    #[test]
+    fn history_with_nulls_and_cashflow_converts() {
+        let input = r#"
+        {
+            "timestamp": [1721793600, 1721880000, 1721966400],
+            "equity": [null, 100129.67, 100231.12],
+            "profit_loss": [null, 0, 101.45],
+            "profit_loss_pct": [null, 0, 0.001013],
+            "base_value": 100129.67,
+            "base_value_asof": "2024-07-24",
+            "timeframe": "1D",
+            "cashflow": {
+                "DIV": [0, null, 12.5]
+            }
+        }
+        "#;
+
+        let frames = serde_json::from_str::<PortfolioHistory>(input)
+            .expect("failed to parse portfolio history")
+            .to_frames()
+            .expect("failed to convert to frames");
+
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[0].time, datetime!(2024-07-24 04:00 UTC));
+        assert_eq!(&*frames[2].base_value_as_of, "2024-07-24");
+        assert_eq!(frames[0].equity, None);
+        assert_eq!(frames[2].profit_loss, Some(101.45));
+        assert_eq!(frames[1].cashflow.get("DIV"), None);
+        assert_eq!(frames[2].cashflow.get("DIV"), Some(&12.5));
+
+        let json = serde_json::to_value(&frames[0]).expect("failed to serialize frame");
+        assert_eq!(json["time"], "2024-07-24T04:00:00Z");
+    }
+
+    #[test]
+    fn mismatched_lengths_are_reported() {
+        let mut history = serde_json::from_str::<PortfolioHistory>(JSON_DATA)
+            .expect("failed to parse portfolio history");
+        history.profit_loss.pop();
+
+        let got = history.to_frames();
+        assert_eq!(
+            got.err(),
+            Some(HistoryError::LengthMismatch {
+                field: "profit_loss".to_string(),
+                expected: 7,
+                found: 6
+            })
+        );
+    }
 }
@@ -0,0 +1,391 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use time::serde::rfc3339;
+use time::{Date, OffsetDateTime};
+
+use crate::trading::portfolio_history::Timeframe;
+use crate::{
+    comma_separated_from_opt_string, date_from_opt_string, serialize_comma_separated,
+    serialize_date,
+};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "ts")]
+use ts_rs::TS;
+
+/// the longest period, in days, Alpaca returns intraday (below `1D`) timeframes for.
+const MAX_INTRADAY_PERIOD_DAYS: u32 = 30;
+
+/// # PeriodUnit
+/// The unit of a portfolio history [`Period`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PeriodUnit {
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+/// # Period
+/// The length of a portfolio history, e.g. `1W` or `3M`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(TS), ts(type = "string"))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema), schemars(with = "String", extend("pattern" = "^[0-9]+[DWMA]$")))]
+pub struct Period {
+    pub amount: u32,
+    pub unit: PeriodUnit,
+}
+
+impl Period {
+    pub fn days(amount: u32) -> Self {
+        Self {
+            amount,
+            unit: PeriodUnit::Day,
+        }
+    }
+
+    pub fn weeks(amount: u32) -> Self {
+        Self {
+            amount,
+            unit: PeriodUnit::Week,
+        }
+    }
+
+    pub fn months(amount: u32) -> Self {
+        Self {
+            amount,
+            unit: PeriodUnit::Month,
+        }
+    }
+
+    pub fn years(amount: u32) -> Self {
+        Self {
+            amount,
+            unit: PeriodUnit::Year,
+        }
+    }
+
+    /// the number of days the period spans, counting a month as 30 days and a year as 365
+    /// like Alpaca's intraday limit does, so `1M` is still allowed with intraday timeframes.
+    pub fn max_days(&self) -> u32 {
+        let days_per_unit = match self.unit {
+            PeriodUnit::Day => 1,
+            PeriodUnit::Week => 7,
+            PeriodUnit::Month => 30,
+            PeriodUnit::Year => 365,
+        };
+        self.amount.saturating_mul(days_per_unit)
+    }
+}
+
+impl Display for Period {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let unit = match self.unit {
+            PeriodUnit::Day => "D",
+            PeriodUnit::Week => "W",
+            PeriodUnit::Month => "M",
+            PeriodUnit::Year => "A",
+        };
+        write!(f, "{}{unit}", self.amount)
+    }
+}
+
+impl FromStr for Period {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid period: {s}");
+        let (split, _) = s.char_indices().last().ok_or_else(invalid)?;
+        let (amount, unit) = s.split_at(split);
+        let amount = amount.parse::<u32>().map_err(|_| invalid())?;
+        let unit = match unit {
+            "D" => PeriodUnit::Day,
+            "W" => PeriodUnit::Week,
+            "M" => PeriodUnit::Month,
+            "A" => PeriodUnit::Year,
+            _ => return Err(invalid()),
+        };
+        Ok(Period { amount, unit })
+    }
+}
+
+impl Serialize for Period {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Period {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let period = String::deserialize(deserializer)?;
+        Period::from_str(&period).map_err(de::Error::custom)
+    }
+}
+
+/// # IntradayReporting
+/// Which timestamps intraday portfolio history points are reported for.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum IntradayReporting {
+    /// regular trading hours only.
+    MarketHours,
+    /// pre-market through after-hours.
+    ExtendedHours,
+    /// every timestamp, around the clock.
+    Continuous,
+}
+
+/// # PnlReset
+/// Whether the baseline of intraday profit and loss resets every day.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum PnlReset {
+    PerDay,
+    NoReset,
+}
+
+/// # PortfolioHistoryQueryError
+/// describes why a portfolio history query would be rejected by Alpaca.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PortfolioHistoryQueryError {
+    /// only two of `period`, `start` and `end` can be given.
+    PeriodWithStartAndEnd,
+    /// `date_end` and `end` both set the end of the history.
+    DateEndWithEnd,
+    StartAfterEnd,
+    /// intraday timeframes are only available for periods up to 30 days.
+    IntradayPeriodTooLong {
+        period: Period,
+        timeframe: Timeframe,
+    },
+    /// the query could not be url encoded.
+    Encode(String),
+}
+
+impl Display for PortfolioHistoryQueryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PortfolioHistoryQueryError::PeriodWithStartAndEnd => {
+                write!(f, "period cannot be used with both start and end")
+            }
+            PortfolioHistoryQueryError::DateEndWithEnd => {
+                write!(f, "date_end and end cannot be used together")
+            }
+            PortfolioHistoryQueryError::StartAfterEnd => write!(f, "start must be before end"),
+            PortfolioHistoryQueryError::IntradayPeriodTooLong { period, timeframe } => write!(
+                f,
+                "timeframe {timeframe} is only available for periods up to \
+                 {MAX_INTRADAY_PERIOD_DAYS} days, got {period}"
+            ),
+            PortfolioHistoryQueryError::Encode(why) => write!(f, "failed to encode query: {why}"),
+        }
+    }
+}
+
+impl std::error::Error for PortfolioHistoryQueryError {}
+
+/// # PortfolioHistoryQuery
+/// the query parameters for GET /v2/account/portfolio/history.
+/// Unset fields are left out and fall back to the server defaults.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "ts", derive(TS))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct PortfolioHistoryQuery {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub period: Option<Period>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub timeframe: Option<Timeframe>,
+    /// the date the history ends on. Superseded by `end`.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_date",
+        deserialize_with = "date_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::FullDate>")
+    )]
+    pub date_end: Option<Date>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "rfc3339::option"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::DateTime>")
+    )]
+    pub start: Option<OffsetDateTime>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "rfc3339::option"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "Option<crate::schema::DateTime>")
+    )]
+    pub end: Option<OffsetDateTime>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub intraday_reporting: Option<IntradayReporting>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(optional))]
+    pub pnl_reset: Option<PnlReset>,
+    /// the activity types to include in `cashflow`, e.g. `DIV`, or `ALL`/`NONE`.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_comma_separated",
+        deserialize_with = "comma_separated_from_opt_string"
+    )]
+    #[cfg_attr(feature = "ts", ts(optional, type = "string"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub cashflow_types: Option<Vec<String>>,
+}
+
+impl PortfolioHistoryQuery {
+    /// checks the combinations of parameters Alpaca rejects.
+    pub fn validate(&self) -> Result<(), PortfolioHistoryQueryError> {
+        if self.period.is_some() && self.start.is_some() && self.end.is_some() {
+            return Err(PortfolioHistoryQueryError::PeriodWithStartAndEnd);
+        }
+        if self.date_end.is_some() && self.end.is_some() {
+            return Err(PortfolioHistoryQueryError::DateEndWithEnd);
+        }
+        if let (Some(start), Some(end)) = (self.start, self.end) {
+            if start > end {
+                return Err(PortfolioHistoryQueryError::StartAfterEnd);
+            }
+        }
+        if let (Some(period), Some(timeframe)) = (self.period, self.timeframe) {
+            if timeframe != Timeframe::OneDay && period.max_days() > MAX_INTRADAY_PERIOD_DAYS {
+                return Err(PortfolioHistoryQueryError::IntradayPeriodTooLong {
+                    period,
+                    timeframe,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// validates the query and encodes it as a url query string, without the leading `?`.
+    pub fn to_query_string(&self) -> Result<String, PortfolioHistoryQueryError> {
+        self.validate()?;
+        serde_urlencoded::to_string(self)
+            .map_err(|why| PortfolioHistoryQueryError::Encode(why.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use time::macros::{date, datetime};
+
+    use super::{
+        IntradayReporting, Period, PnlReset, PortfolioHistoryQuery, PortfolioHistoryQueryError,
+    };
+    use crate::trading::portfolio_history::Timeframe;
+
+    #[test]
+    fn period_parses_and_displays() {
+        assert_eq!(Period::from_str("3M"), Ok(Period::months(3)));
+        assert_eq!(Period::from_str("1A"), Ok(Period::years(1)));
+        assert!(Period::from_str("M").is_err());
+        assert!(Period::from_str("2Q").is_err());
+        assert!(Period::from_str("").is_err());
+        assert!(Period::from_str("1é").is_err());
+
+        let query = serde_json::from_str::<PortfolioHistoryQuery>(r#"{"period":"1é"}"#);
+        assert!(query.is_err());
+        assert_eq!(Period::weeks(2).to_string(), "2W");
+    }
+
+    #[test]
+    fn query_encodes() {
+        let query = PortfolioHistoryQuery {
+            period: Some(Period::weeks(1)),
+            timeframe: Some(Timeframe::OneHour),
+            date_end: Some(date!(2024 - 07 - 26)),
+            intraday_reporting: Some(IntradayReporting::ExtendedHours),
+            pnl_reset: Some(PnlReset::NoReset),
+            cashflow_types: Some(vec!["DIV".to_string(), "FEE".to_string()]),
+            ..Default::default()
+        };
+
+        let wanted = "period=1W&timeframe=1H&date_end=2024-07-26\
+                      &intraday_reporting=extended_hours&pnl_reset=no_reset&cashflow_types=DIV%2CFEE";
+        assert_eq!(query.to_query_string(), Ok(wanted.to_string()));
+
+        let query = PortfolioHistoryQuery {
+            start: Some(datetime!(2024-07-01 0:00 UTC)),
+            end: Some(datetime!(2024-07-26 0:00 UTC)),
+            ..Default::default()
+        };
+        assert_eq!(
+            query.to_query_string(),
+            Ok("start=2024-07-01T00%3A00%3A00Z&end=2024-07-26T00%3A00%3A00Z".to_string())
+        );
+    }
+
+    #[test]
+    fn rejected_combinations() {
+        let query = PortfolioHistoryQuery {
+            period: Some(Period::months(1)),
+            start: Some(datetime!(2024-07-01 0:00 UTC)),
+            end: Some(datetime!(2024-07-26 0:00 UTC)),
+            ..Default::default()
+        };
+        assert_eq!(
+            query.validate(),
+            Err(PortfolioHistoryQueryError::PeriodWithStartAndEnd)
+        );
+
+        let query = PortfolioHistoryQuery {
+            start: Some(datetime!(2024-07-26 0:00 UTC)),
+            end: Some(datetime!(2024-07-01 0:00 UTC)),
+            ..Default::default()
+        };
+        assert_eq!(
+            query.validate(),
+            Err(PortfolioHistoryQueryError::StartAfterEnd)
+        );
+
+        let query = PortfolioHistoryQuery {
+            period: Some(Period::months(1)),
+            timeframe: Some(Timeframe::FiveMinutes),
+            ..Default::default()
+        };
+        assert_eq!(query.validate(), Ok(()));
+
+        let query = PortfolioHistoryQuery {
+            period: Some(Period::months(3)),
+            timeframe: Some(Timeframe::FiveMinutes),
+            ..Default::default()
+        };
+        assert!(matches!(
+            query.to_query_string(),
+            Err(PortfolioHistoryQueryError::IntradayPeriodTooLong { .. })
+        ));
+    }
+}
@@ -0,0 +1,398 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use time::{Duration, OffsetDateTime, UtcOffset};
+
+use crate::market_data::PriceBar;
+use crate::trading::portfolio_history::{HistoryError, HistoryFrame, PortfolioHistory, Timeframe};
+
+/// # ResampleError
+/// describes why a portfolio history could not be resampled.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResampleError {
+    /// the history's timeframe is not one Alpaca documents.
+    UnknownTimeframe(String),
+    /// points can only be combined into a coarser timeframe.
+    FinerTimeframe {
+        from: Timeframe,
+        to: Timeframe,
+    },
+    History(HistoryError),
+}
+
+impl Display for ResampleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResampleError::UnknownTimeframe(timeframe) => {
+                write!(f, "cannot resample unknown timeframe {timeframe}")
+            }
+            ResampleError::FinerTimeframe { from, to } => {
+                write!(f, "cannot resample {from} into the finer timeframe {to}")
+            }
+            ResampleError::History(why) => write!(f, "{why}"),
+        }
+    }
+}
+
+impl std::error::Error for ResampleError {}
+
+impl From<HistoryError> for ResampleError {
+    fn from(why: HistoryError) -> Self {
+        ResampleError::History(why)
+    }
+}
+
+/// the start of the `width` long window `time` falls in, with windows aligned to
+/// midnight at `offset`.
+fn bucket_start(time: OffsetDateTime, width: Duration, offset: UtcOffset) -> OffsetDateTime {
+    let local_seconds = time.unix_timestamp() + i64::from(offset.whole_seconds());
+    let into_bucket = local_seconds.rem_euclid(width.whole_seconds());
+    time - Duration::seconds(into_bucket) - Duration::nanoseconds(i64::from(time.nanosecond()))
+}
+
+/// sums the points that are present, `None` if none are.
+fn sum(points: impl Iterator<Item = Option<f64>>) -> Option<f64> {
+    points
+        .flatten()
+        .fold(None, |total, point| Some(total.unwrap_or(0.0) + point))
+}
+
+/// combines the frames of one window.
+fn combine(time: OffsetDateTime, frames: &[HistoryFrame]) -> HistoryFrame {
+    let last = frames.last().expect("windows are never empty");
+
+    let profit_loss_percent = frames
+        .iter()
+        .filter_map(|frame| frame.profit_loss_percent)
+        .fold(None, |total: Option<f64>, pct| {
+            Some((1.0 + total.unwrap_or(0.0)) * (1.0 + pct) - 1.0)
+        });
+
+    let mut cashflow: HashMap<String, f64> = HashMap::new();
+    for frame in frames {
+        for (kind, amount) in &frame.cashflow {
+            *cashflow.entry(kind.clone()).or_default() += amount;
+        }
+    }
+
+    HistoryFrame {
+        time,
+        equity: frames.iter().rev().find_map(|frame| frame.equity),
+        profit_loss: sum(frames.iter().map(|frame| frame.profit_loss)),
+        profit_loss_percent,
+        base_value: last.base_value,
+        base_value_as_of: Arc::clone(&last.base_value_as_of),
+        cashflow,
+    }
+}
+
+/// resamples time ordered frames into windows of `to`, aligned to midnight at `offset`
+/// (e.g. New York time for daily windows of a US equity account).
+/// Each window is stamped with its start and keeps the last equity, the summed
+/// profit or loss and cashflows, and the compounded profit or loss percent.
+/// Missing points are skipped, a window without any is `None`.
+pub fn resample_frames(
+    frames: &[HistoryFrame],
+    to: Timeframe,
+    offset: UtcOffset,
+) -> Vec<HistoryFrame> {
+    let width = to.duration();
+    let mut resampled = vec![];
+
+    let mut window_start = 0;
+    for idx in 1..=frames.len() {
+        let start = bucket_start(frames[window_start].time, width, offset);
+        let ends_window =
+            idx == frames.len() || bucket_start(frames[idx].time, width, offset) != start;
+        if ends_window {
+            resampled.push(combine(start, &frames[window_start..idx]));
+            window_start = idx;
+        }
+    }
+
+    resampled
+}
+
+impl PortfolioHistory {
+    /// resamples the history into the coarser timeframe `to`, with windows aligned to UTC.
+    /// See [`resample_frames`] for how points are combined.
+    pub fn resample(&self, to: Timeframe) -> Result<PortfolioHistory, ResampleError> {
+        self.resample_in(to, UtcOffset::UTC)
+    }
+
+    /// like `resample`, with windows aligned to midnight at `offset`.
+    pub fn resample_in(
+        &self,
+        to: Timeframe,
+        offset: UtcOffset,
+    ) -> Result<PortfolioHistory, ResampleError> {
+        let from = Timeframe::from_str(&self.timeframe)
+            .map_err(|_| ResampleError::UnknownTimeframe(self.timeframe.clone()))?;
+        if to.duration() < from.duration() {
+            return Err(ResampleError::FinerTimeframe { from, to });
+        }
+
+        let frames = resample_frames(&self.clone().to_frames()?, to, offset);
+        let cashflow = self
+            .cashflow
+            .keys()
+            .map(|kind| {
+                let points = frames
+                    .iter()
+                    .map(|frame| frame.cashflow.get(kind).copied())
+                    .collect();
+                (kind.clone(), points)
+            })
+            .collect();
+
+        Ok(PortfolioHistory {
+            timestamp: frames
+                .iter()
+                .map(|frame| frame.time.unix_timestamp())
+                .collect(),
+            equity: frames.iter().map(|frame| frame.equity).collect(),
+            profit_loss: frames.iter().map(|frame| frame.profit_loss).collect(),
+            profit_loss_pct: frames
+                .iter()
+                .map(|frame| frame.profit_loss_percent)
+                .collect(),
+            base_value: self.base_value,
+            base_value_asof: self.base_value_asof.clone(),
+            timeframe: to.to_string(),
+            cashflow,
+            #[cfg(feature = "extra_fields")]
+            extra: self.extra.clone(),
+        })
+    }
+
+    /// joins the equity of the history with the closes of `bars`, see [`align`].
+    pub fn align<B: PriceBar>(
+        &self,
+        bars: &[B],
+        fill: GapFill,
+    ) -> Result<Vec<AlignedPoint>, HistoryError> {
+        self.check_lengths()?;
+
+        let mut equity = Vec::with_capacity(self.timestamp.len());
+        for (timestamp, point) in self.timestamp.iter().zip(&self.equity) {
+            let time = OffsetDateTime::from_unix_timestamp(*timestamp)
+                .map_err(HistoryError::InvalidTimestamp)?;
+            equity.push((time, *point));
+        }
+        Ok(join(equity, bars, fill))
+    }
+}
+
+/// # GapFill
+/// What to do with timestamps only one of two aligned series has a value for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GapFill {
+    /// keep the point, with the missing side `None`.
+    Leave,
+    /// carry the last known value of the missing side forward.
+    /// Gaps before a series' first value stay `None`.
+    Forward,
+    /// drop points missing either side.
+    Drop,
+}
+
+/// # AlignedPoint
+/// the equity of an account and the close of a bar at the same time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlignedPoint {
+    pub time: OffsetDateTime,
+    pub equity: Option<f64>,
+    pub close: Option<f64>,
+}
+
+/// joins the equity of `frames` with the closes of `bars`, with a point for every frame
+/// in time order. Each point gets the close of the last bar stamped after the previous frame
+/// and at or before its own, so bars stamped on another convention still line up, e.g. daily
+/// crypto bars at UTC midnight with a `1D` history at New York midnight. A frame without
+/// such a bar is a gap.
+/// Both series must share a timeframe, e.g. a `1D` history and daily bars;
+/// resample the history first if they do not.
+pub fn align<B: PriceBar>(frames: &[HistoryFrame], bars: &[B], fill: GapFill) -> Vec<AlignedPoint> {
+    let equity = frames
+        .iter()
+        .map(|frame| (frame.time, frame.equity))
+        .collect();
+    join(equity, bars, fill)
+}
+
+pub(crate) fn join<B: PriceBar>(
+    mut equity: Vec<(OffsetDateTime, Option<f64>)>,
+    bars: &[B],
+    fill: GapFill,
+) -> Vec<AlignedPoint> {
+    equity.sort_by_key(|(time, _)| *time);
+    let mut closes: Vec<(OffsetDateTime, f64)> = bars
+        .iter()
+        .map(|bar| (bar.timestamp(), bar.close()))
+        .collect();
+    closes.sort_by_key(|(time, _)| *time);
+
+    let mut next_close = 0;
+    let points = equity.into_iter().map(|(time, equity)| {
+        // the last bar since the previous point, stamped at or before this one.
+        let mut close = None;
+        while let Some((bar_time, bar_close)) = closes.get(next_close) {
+            if *bar_time > time {
+                break;
+            }
+            close = Some(*bar_close);
+            next_close += 1;
+        }
+        AlignedPoint {
+            time,
+            equity,
+            close,
+        }
+    });
+
+    match fill {
+        GapFill::Leave => points.collect(),
+        GapFill::Drop => points
+            .filter(|point| point.equity.is_some() && point.close.is_some())
+            .collect(),
+        GapFill::Forward => {
+            let (mut equity, mut close) = (None, None);
+            points
+                .map(|mut point| {
+                    equity = point.equity.or(equity);
+                    close = point.close.or(close);
+                    point.equity = equity;
+                    point.close = close;
+                    point
+                })
+                .collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::{datetime, offset};
+
+    use super::{align, GapFill, ResampleError};
+    use crate::market_data::stock::Bar;
+    use crate::trading::portfolio_history::{PortfolioHistory, Timeframe};
+
+    // two trading days of hourly points, the second hour of the first day missing.
+    const HOURLY: &str = r#"
+    {
+        "timestamp": [1721827800, 1721831400, 1721835000, 1721914200, 1721917800],
+        "equity": [100.0, null, 103.0, 101.0, 104.0],
+        "profit_loss": [0.0, null, 3.0, -2.0, 3.0],
+        "profit_loss_pct": [0.0, null, 0.03, -0.01941748, 0.02970297],
+        "base_value": 100.0,
+        "base_value_asof": "2024-07-23",
+        "timeframe": "1H",
+        "cashflow": {
+            "DIV": [0.0, null, 1.5, 0.0, 2.0]
+        }
+    }
+    "#;
+
+    fn bar(time: time::OffsetDateTime, close: f64) -> Bar {
+        Bar {
+            timestamp: time,
+            open: close,
+            high: close,
+            low: close,
+            close,
+            volume: 1000,
+            trade_count: 10,
+            vwap: close,
+        }
+    }
+
+    #[test]
+    fn resamples_to_daily() {
+        let history = serde_json::from_str::<PortfolioHistory>(HOURLY)
+            .expect("failed to parse portfolio history");
+        let daily = history
+            .resample_in(Timeframe::OneDay, offset!(-4))
+            .expect("failed to resample");
+
+        assert_eq!(daily.timeframe, "1D");
+        assert_eq!(
+            daily.timestamp,
+            vec![
+                datetime!(2024-07-24 04:00 UTC).unix_timestamp(),
+                datetime!(2024-07-25 04:00 UTC).unix_timestamp()
+            ]
+        );
+        assert_eq!(daily.equity, vec![Some(103.0), Some(104.0)]);
+        assert_eq!(daily.profit_loss, vec![Some(3.0), Some(1.0)]);
+        assert_eq!(daily.cashflow["DIV"], vec![Some(1.5), Some(2.0)]);
+
+        let pct = daily.profit_loss_pct[1].expect("missing percent");
+        assert!((pct - (104.0 / 103.0 - 1.0)).abs() < 1e-6, "got {pct}");
+
+        assert_eq!(
+            daily.resample(Timeframe::OneHour).err(),
+            Some(ResampleError::FinerTimeframe {
+                from: Timeframe::OneDay,
+                to: Timeframe::OneHour
+            })
+        );
+    }
+
+    #[test]
+    fn aligns_with_bars() {
+        let history = serde_json::from_str::<PortfolioHistory>(HOURLY)
+            .expect("failed to parse portfolio history");
+        let bars = vec![
+            bar(datetime!(2024-07-24 13:30 UTC), 540.0),
+            bar(datetime!(2024-07-24 14:30 UTC), 541.0),
+            bar(datetime!(2024-07-25 14:30 UTC), 542.0),
+        ];
+
+        let left = history
+            .align(&bars, GapFill::Leave)
+            .expect("failed to align");
+        assert_eq!(left.len(), 5);
+        assert_eq!(left[1].equity, None);
+        assert_eq!(left[1].close, Some(541.0));
+        assert_eq!(left[2].close, None);
+
+        let forward = history
+            .align(&bars, GapFill::Forward)
+            .expect("failed to align");
+        assert_eq!(forward[1].equity, Some(100.0));
+        assert_eq!(forward[2].close, Some(541.0));
+
+        let dropped = history
+            .align(&bars, GapFill::Drop)
+            .expect("failed to align");
+        assert_eq!(dropped.len(), 2);
+
+        let frames = history.to_frames().expect("failed to convert to frames");
+        assert_eq!(align(&frames, &bars, GapFill::Leave), left);
+    }
+
+    #[test]
+    fn aligns_bars_stamped_on_another_convention() {
+        let history = serde_json::from_str::<PortfolioHistory>(HOURLY)
+            .expect("failed to parse portfolio history")
+            .resample_in(Timeframe::OneDay, offset!(-4))
+            .expect("failed to resample");
+        // daily crypto bars start at UTC midnight, the history's days at New York midnight.
+        let bars = vec![
+            bar(datetime!(2024-07-23 00:00 UTC), 60_000.0),
+            bar(datetime!(2024-07-24 00:00 UTC), 61_000.0),
+            bar(datetime!(2024-07-25 00:00 UTC), 62_000.0),
+        ];
+
+        let dropped = history
+            .align(&bars, GapFill::Drop)
+            .expect("failed to align");
+        assert_eq!(dropped.len(), 2);
+        assert_eq!(dropped[0].time, datetime!(2024-07-24 04:00 UTC));
+        assert_eq!(dropped[0].close, Some(61_000.0));
+        assert_eq!(dropped[1].close, Some(62_000.0));
+    }
+}